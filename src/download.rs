@@ -0,0 +1,171 @@
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+
+/// Opt-in archiving of a channel's uploads via `yt-dlp`, configured under `download` in
+/// config.json. Disabled unless a caller explicitly asks `YouTubeClient::download_artist`
+/// to run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadConfig {
+    /// Binary name or path; resolved via PATH when just a name (e.g. "yt-dlp").
+    #[serde(default = "default_binary")]
+    pub binary: String,
+    #[serde(default = "default_output_template")]
+    pub output_template: String,
+    #[serde(default = "default_true")]
+    pub audio_only: bool,
+    #[serde(default = "default_format")]
+    pub format: String,
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+    /// Max tracks to pull per channel per run.
+    #[serde(default = "default_track_limit")]
+    pub track_limit: usize,
+}
+
+fn default_binary() -> String { "yt-dlp".to_string() }
+fn default_output_template() -> String { "downloads/%(uploader)s/%(title)s.%(ext)s".to_string() }
+fn default_true() -> bool { true }
+fn default_format() -> String { "bestaudio/m4a".to_string() }
+fn default_concurrency() -> usize { 2 }
+fn default_track_limit() -> usize { 10 }
+
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        Self {
+            binary: default_binary(),
+            output_template: default_output_template(),
+            audio_only: default_true(),
+            format: default_format(),
+            concurrency: default_concurrency(),
+            track_limit: default_track_limit(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct YtDlpEntry {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub upload_date: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct YtDlpPlaylist {
+    #[serde(default)]
+    entries: Vec<YtDlpEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DownloadResult {
+    pub track_id: String,
+    pub title: String,
+    pub path: Option<String>,
+    pub success: bool,
+}
+
+/// One artist's worth of download results, as written to the manifest JSON.
+#[derive(Debug, Serialize)]
+pub struct DownloadManifestEntry {
+    pub artist: String,
+    pub channel_id: String,
+    pub tracks: Vec<DownloadResult>,
+}
+
+/// Writes a JSON manifest describing what was downloaded for each artist, so a later run
+/// (or another tool) can see what's on disk without re-scanning `downloads/`.
+pub fn write_manifest(entries: &[DownloadManifestEntry], path: &std::path::Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+    let json = serde_json::to_string_pretty(entries).context("Failed to serialize download manifest")?;
+    std::fs::write(path, json).with_context(|| format!("Failed to write manifest to {}", path.display()))?;
+    Ok(())
+}
+
+pub fn init_downloads_table(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS downloads (
+            track_id TEXT PRIMARY KEY,
+            channel_id TEXT NOT NULL,
+            title TEXT NOT NULL,
+            path TEXT,
+            success INTEGER NOT NULL,
+            downloaded_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+pub fn already_downloaded(conn: &Connection, track_id: &str) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM downloads WHERE track_id = ?1 AND success = 1",
+        params![track_id],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+pub fn record_download(conn: &Connection, channel_id: &str, result: &DownloadResult) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO downloads (track_id, channel_id, title, path, success, downloaded_at)
+         VALUES (?, ?, ?, ?, ?, datetime('now'))",
+        params![result.track_id, channel_id, result.title, result.path, result.success as i64],
+    )?;
+    Ok(())
+}
+
+/// Enumerates a channel's uploads via `yt-dlp --dump-single-json --flat-playlist`, the same
+/// approach the `youtube_dl` crate uses to avoid downloading anything just to list titles.
+pub async fn list_channel_uploads(binary: &str, channel_id: &str, limit: usize) -> Result<Vec<YtDlpEntry>> {
+    let url = format!("https://www.youtube.com/channel/{channel_id}/videos");
+    let output = Command::new(binary)
+        .arg("--dump-single-json")
+        .arg("--flat-playlist")
+        .arg("--playlist-end").arg(limit.to_string())
+        .arg(&url)
+        .output()
+        .await
+        .with_context(|| format!("Failed to run {binary}. Is yt-dlp installed and on PATH?"))?;
+
+    if !output.status.success() {
+        anyhow::bail!("{binary} exited with {}: {}", output.status, String::from_utf8_lossy(&output.stderr));
+    }
+
+    let playlist: YtDlpPlaylist = serde_json::from_slice(&output.stdout)
+        .context("Failed to parse yt-dlp JSON output")?;
+    Ok(playlist.entries.into_iter().take(limit).collect())
+}
+
+/// Downloads `video_id` and returns the actual path yt-dlp wrote it to, via
+/// `--print after_move:filepath` (the path after any post-processing/move, e.g. `-x`
+/// audio extraction), rather than the unexpanded output template.
+pub async fn download_track(config: &DownloadConfig, video_id: &str) -> Result<String> {
+    let mut cmd = Command::new(&config.binary);
+    cmd.arg("-o").arg(&config.output_template)
+        .arg("-f").arg(&config.format)
+        .arg("--print").arg("after_move:filepath");
+    if config.audio_only {
+        cmd.arg("-x");
+    }
+    cmd.arg(format!("https://www.youtube.com/watch?v={video_id}"));
+
+    let output = cmd.output().await
+        .with_context(|| format!("Failed to run {} for {video_id}", config.binary))?;
+
+    if !output.status.success() {
+        anyhow::bail!("{} failed for {video_id}: {}", config.binary, String::from_utf8_lossy(&output.stderr));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.lines().rev().find(|line| !line.trim().is_empty())
+        .map(|line| line.trim().to_string())
+        .with_context(|| format!("{} did not print a file path for {video_id}", config.binary))
+}
@@ -0,0 +1,121 @@
+/// An artists-file line can be a plain name to search for, or a direct YouTube link that
+/// already identifies a channel (URL, @handle, legacy custom URL, or a video/playlist
+/// whose owning channel we want).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArtistEntry {
+    Name(String),
+    ChannelId(String),
+    Handle(String),
+    CustomUrl(String),
+    Video(String),
+    Playlist(String),
+}
+
+fn first_segment(rest: &str) -> &str {
+    rest.split(['/', '?', '&']).next().unwrap_or(rest)
+}
+
+fn strip_any<'a>(raw: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes.iter().find_map(|prefix| raw.strip_prefix(prefix))
+}
+
+/// Classifies a single (trimmed) artists-file entry.
+pub fn classify_entry(raw: &str) -> ArtistEntry {
+    let raw = raw.trim();
+
+    if let Some(rest) = strip_any(raw, &[
+        "https://www.youtube.com/channel/",
+        "https://youtube.com/channel/",
+        "http://www.youtube.com/channel/",
+        "http://youtube.com/channel/",
+        "https://music.youtube.com/channel/",
+    ]) {
+        return ArtistEntry::ChannelId(first_segment(rest).to_string());
+    }
+
+    if let Some(rest) = strip_any(raw, &[
+        "https://www.youtube.com/@",
+        "https://youtube.com/@",
+        "https://music.youtube.com/@",
+    ]) {
+        return ArtistEntry::Handle(format!("@{}", first_segment(rest)));
+    }
+
+    if raw.starts_with('@') && !raw.contains(' ') && !raw.contains('/') {
+        return ArtistEntry::Handle(raw.to_string());
+    }
+
+    if let Some(rest) = strip_any(raw, &[
+        "https://www.youtube.com/c/",
+        "https://youtube.com/c/",
+        "https://www.youtube.com/user/",
+        "https://youtube.com/user/",
+    ]) {
+        return ArtistEntry::CustomUrl(first_segment(rest).to_string());
+    }
+
+    if let Some(rest) = strip_any(raw, &["https://youtu.be/", "http://youtu.be/"]) {
+        return ArtistEntry::Video(first_segment(rest).to_string());
+    }
+
+    if raw.contains("watch?v=") {
+        if let Some(idx) = raw.find("v=") {
+            let id = raw[idx + 2..].split('&').next().unwrap_or_default();
+            if !id.is_empty() {
+                return ArtistEntry::Video(id.to_string());
+            }
+        }
+    }
+
+    if raw.contains("playlist?list=") {
+        if let Some(idx) = raw.find("list=") {
+            let id = raw[idx + 5..].split('&').next().unwrap_or_default();
+            if !id.is_empty() {
+                return ArtistEntry::Playlist(id.to_string());
+            }
+        }
+    }
+
+    ArtistEntry::Name(raw.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_plain_names() {
+        assert_eq!(classify_entry("Tool"), ArtistEntry::Name("Tool".to_string()));
+    }
+
+    #[test]
+    fn classifies_channel_urls() {
+        assert_eq!(
+            classify_entry("https://www.youtube.com/channel/UCabc123?si=x"),
+            ArtistEntry::ChannelId("UCabc123".to_string())
+        );
+        assert_eq!(
+            classify_entry("https://music.youtube.com/channel/UCabc123"),
+            ArtistEntry::ChannelId("UCabc123".to_string())
+        );
+    }
+
+    #[test]
+    fn classifies_handles() {
+        assert_eq!(classify_entry("https://www.youtube.com/@toolband"), ArtistEntry::Handle("@toolband".to_string()));
+        assert_eq!(classify_entry("@toolband"), ArtistEntry::Handle("@toolband".to_string()));
+    }
+
+    #[test]
+    fn classifies_legacy_custom_urls() {
+        assert_eq!(classify_entry("https://www.youtube.com/c/ToolVEVO"), ArtistEntry::CustomUrl("ToolVEVO".to_string()));
+        assert_eq!(classify_entry("https://www.youtube.com/user/ToolVEVO"), ArtistEntry::CustomUrl("ToolVEVO".to_string()));
+    }
+
+    #[test]
+    fn classifies_video_and_playlist_links() {
+        assert_eq!(classify_entry("https://www.youtube.com/watch?v=abc123&t=10s"), ArtistEntry::Video("abc123".to_string()));
+        assert_eq!(classify_entry("https://youtu.be/abc123"), ArtistEntry::Video("abc123".to_string()));
+        assert_eq!(classify_entry("https://www.youtube.com/playlist?list=PLxyz"), ArtistEntry::Playlist("PLxyz".to_string()));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::youtube::Artist;
+use anyhow::{Context, Result};
+use log::{info, warn};
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use serde::Deserialize;
+use std::io::Cursor;
+use std::path::Path;
+
+fn channel_feed_url(channel_id: &str) -> String {
+    format!("https://www.youtube.com/feeds/videos.xml?channel_id={channel_id}")
+}
+
+/// Writes `artists` as an OPML outline listing each channel's YouTube RSS feed, loadable
+/// into any podcast/feed reader. Mirrors the `rss` export in rustypipe.
+pub fn write_opml(artists: &[Artist], path: &Path) -> Result<()> {
+    let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    writer.write_event(Event::Start(BytesStart::new("opml").with_attributes([("version", "2.0")])))?;
+    writer.write_event(Event::Start(BytesStart::new("head")))?;
+    writer.write_event(Event::Start(BytesStart::new("title")))?;
+    writer.write_event(Event::Text(BytesText::new("YouTube Music Subscriptions")))?;
+    writer.write_event(Event::End(BytesEnd::new("title")))?;
+    writer.write_event(Event::End(BytesEnd::new("head")))?;
+    writer.write_event(Event::Start(BytesStart::new("body")))?;
+
+    let mut written = 0;
+    for artist in artists {
+        if artist.channel_id.starts_with("mock_") {
+            continue;
+        }
+
+        let feed_url = channel_feed_url(&artist.channel_id);
+        let mut outline = BytesStart::new("outline");
+        outline.push_attribute(("text", artist.name.as_str()));
+        outline.push_attribute(("title", artist.name.as_str()));
+        outline.push_attribute(("type", "rss"));
+        outline.push_attribute(("xmlUrl", feed_url.as_str()));
+        writer.write_event(Event::Empty(outline))?;
+        written += 1;
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("body")))?;
+    writer.write_event(Event::End(BytesEnd::new("opml")))?;
+
+    let bytes = writer.into_inner().into_inner();
+    std::fs::write(path, bytes).with_context(|| format!("Failed to write OPML to {}", path.display()))?;
+    info!("Wrote OPML with {written} channels to {}", path.display());
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomFeed {
+    #[serde(default)]
+    title: String,
+    #[serde(rename = "entry", default)]
+    entries: Vec<AtomEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct AtomEntry {
+    // YouTube's feed XML declares this element under the `yt:` namespace
+    // (`<yt:videoId>`); quick-xml's serde support doesn't always strip namespace prefixes,
+    // so accept either spelling and default to empty rather than hard-failing the whole
+    // feed if a future feed variant omits it.
+    #[serde(rename = "videoId", alias = "yt:videoId", default)]
+    pub(crate) video_id: String,
+    pub(crate) title: String,
+    link: AtomLink,
+    pub(crate) published: String,
+    author: AtomAuthor,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct AtomLink {
+    #[serde(rename = "@href")]
+    href: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct AtomAuthor {
+    name: String,
+}
+
+pub(crate) async fn fetch_channel_entries(client: &reqwest::Client, channel_id: &str) -> Result<Vec<AtomEntry>> {
+    let url = channel_feed_url(channel_id);
+    let body = client.get(&url).send().await?.text().await?;
+    let feed: AtomFeed = quick_xml::de::from_str(&body)
+        .with_context(|| format!("Failed to parse channel feed for {channel_id}"))?;
+    Ok(feed.entries)
+}
+
+/// A channel's Atom feed title plus its most recent uploads, used both as a post-subscribe
+/// sanity check (does `channel_id` actually resolve to a live channel?) and to show the
+/// user what they just subscribed to.
+pub struct ChannelDigest {
+    pub title: String,
+    pub latest_uploads: Vec<(String, String)>,
+}
+
+/// Fetches `channel_id`'s public Atom feed (no API quota cost) and returns its title
+/// alongside the newest `limit` upload titles/publish dates.
+pub async fn fetch_channel_digest(channel_id: &str, limit: usize) -> Result<ChannelDigest> {
+    let client = reqwest::Client::new();
+    let url = channel_feed_url(channel_id);
+    let body = client.get(&url).send().await?.text().await?;
+    let feed: AtomFeed = quick_xml::de::from_str(&body)
+        .with_context(|| format!("Failed to parse channel feed for {channel_id}"))?;
+
+    let latest_uploads = feed.entries.into_iter()
+        .take(limit)
+        .map(|entry| (entry.title, entry.published))
+        .collect();
+
+    Ok(ChannelDigest { title: feed.title, latest_uploads })
+}
+
+/// Renders an RFC 3339 publish timestamp as a rough "N days/hours ago" string, falling
+/// back to the raw timestamp if it can't be parsed.
+pub fn humanize_published(published: &str) -> String {
+    let Ok(when) = chrono_like_parse(published) else {
+        return published.to_string();
+    };
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    let delta = (now - when).max(0);
+
+    if delta < 3600 {
+        format!("{} minutes ago", delta / 60)
+    } else if delta < 86_400 {
+        format!("{} hours ago", delta / 3600)
+    } else {
+        format!("{} days ago", delta / 86_400)
+    }
+}
+
+/// Parses an RFC 3339 timestamp (the format YouTube's Atom feeds use) into Unix seconds,
+/// without pulling in a date/time crate for one field.
+pub(crate) fn chrono_like_parse(published: &str) -> Result<i64> {
+    let (date_part, time_part) = published.split_once('T').context("missing time separator")?;
+    let time_part = time_part.trim_end_matches('Z');
+    let time_part = time_part.split(['+', '-']).next().unwrap_or(time_part);
+
+    let mut date_fields = date_part.split('-');
+    let year: i64 = date_fields.next().context("missing year")?.parse()?;
+    let month: i64 = date_fields.next().context("missing month")?.parse()?;
+    let day: i64 = date_fields.next().context("missing day")?.parse()?;
+
+    let mut time_fields = time_part.split(':');
+    let hour: i64 = time_fields.next().context("missing hour")?.parse()?;
+    let minute: i64 = time_fields.next().context("missing minute")?.parse()?;
+    let second: i64 = time_fields.next().unwrap_or("0").parse().unwrap_or(0);
+
+    // Days since epoch via a standard civil-calendar formula (Howard Hinnant's
+    // days_from_civil), then converted to seconds - avoids pulling in a date/time crate.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days_since_epoch = era * 146_097 + doe - 719_468;
+
+    Ok(days_since_epoch * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+/// Fetches each non-mock artist's channel feed and writes the newest `limit` entries
+/// (across all channels, sorted by publish date) as a single aggregated RSS 2.0 feed.
+pub async fn write_rss(artists: &[Artist], path: &Path, limit: usize) -> Result<()> {
+    let client = reqwest::Client::new();
+    let mut entries = Vec::new();
+
+    for artist in artists {
+        if artist.channel_id.starts_with("mock_") {
+            continue;
+        }
+
+        match fetch_channel_entries(&client, &artist.channel_id).await {
+            Ok(channel_entries) => entries.extend(channel_entries),
+            Err(e) => warn!("Failed to fetch feed for {}: {e}", artist.name),
+        }
+    }
+
+    entries.sort_by(|a, b| b.published.cmp(&a.published));
+    entries.truncate(limit);
+
+    let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new("rss").with_attributes([("version", "2.0")])))?;
+    writer.write_event(Event::Start(BytesStart::new("channel")))?;
+    writer.write_event(Event::Start(BytesStart::new("title")))?;
+    writer.write_event(Event::Text(BytesText::new("YouTube Music Subscriptions")))?;
+    writer.write_event(Event::End(BytesEnd::new("title")))?;
+
+    for entry in &entries {
+        writer.write_event(Event::Start(BytesStart::new("item")))?;
+
+        writer.write_event(Event::Start(BytesStart::new("title")))?;
+        writer.write_event(Event::Text(BytesText::new(&entry.title)))?;
+        writer.write_event(Event::End(BytesEnd::new("title")))?;
+
+        writer.write_event(Event::Start(BytesStart::new("link")))?;
+        writer.write_event(Event::Text(BytesText::new(&entry.link.href)))?;
+        writer.write_event(Event::End(BytesEnd::new("link")))?;
+
+        writer.write_event(Event::Start(BytesStart::new("pubDate")))?;
+        writer.write_event(Event::Text(BytesText::new(&entry.published)))?;
+        writer.write_event(Event::End(BytesEnd::new("pubDate")))?;
+
+        writer.write_event(Event::Start(BytesStart::new("description")))?;
+        writer.write_event(Event::Text(BytesText::new(&entry.author.name)))?;
+        writer.write_event(Event::End(BytesEnd::new("description")))?;
+
+        writer.write_event(Event::End(BytesEnd::new("item")))?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("channel")))?;
+    writer.write_event(Event::End(BytesEnd::new("rss")))?;
+
+    let bytes = writer.into_inner().into_inner();
+    std::fs::write(path, bytes).with_context(|| format!("Failed to write RSS to {}", path.display()))?;
+    info!("Wrote aggregated RSS feed with {} items to {}", entries.len(), path.display());
+    Ok(())
+}
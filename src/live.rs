@@ -0,0 +1,187 @@
+use crate::youtube::Artist;
+use anyhow::Result;
+use colored::*;
+use futures::stream::{self, StreamExt};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Per-artist watch behavior: how often to poll for live broadcasts, and whether to also
+/// tail each live chat's messages once a broadcast is found.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchConfig {
+    #[serde(default = "default_poll_interval_seconds")]
+    pub poll_interval_seconds: u64,
+    #[serde(default)]
+    pub follow_chat: bool,
+}
+
+fn default_poll_interval_seconds() -> u64 {
+    300
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_seconds: default_poll_interval_seconds(),
+            follow_chat: false,
+        }
+    }
+}
+
+/// Checks whether a channel currently has a live broadcast via the search endpoint's
+/// `eventType=live` filter. Returns `(video_id, title)` when one is found.
+async fn check_live(client: &reqwest::Client, api_key: &str, channel_id: &str) -> Result<Option<(String, String)>> {
+    if api_key.is_empty() {
+        return Ok(None);
+    }
+
+    let url = format!(
+        "https://www.googleapis.com/youtube/v3/search?part=snippet&channelId={channel_id}&eventType=live&type=video&key={api_key}"
+    );
+    let response = client.get(&url).send().await?;
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+
+    let data: serde_json::Value = response.json().await?;
+    if let Some(item) = data["items"].as_array().and_then(|items| items.first()) {
+        if let Some(video_id) = item["id"]["videoId"].as_str() {
+            let title = item["snippet"]["title"].as_str().unwrap_or("Live stream").to_string();
+            return Ok(Some((video_id.to_string(), title)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Looks up the active live chat id for `video_id` (only present while the broadcast is
+/// live), needed to tail its messages via `liveChat/messages`.
+async fn get_live_chat_id(client: &reqwest::Client, api_key: &str, video_id: &str) -> Result<Option<String>> {
+    let url = format!(
+        "https://www.googleapis.com/youtube/v3/videos?part=liveStreamingDetails&id={video_id}&key={api_key}"
+    );
+    let response = client.get(&url).send().await?;
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+
+    let data: serde_json::Value = response.json().await?;
+    Ok(data["items"].as_array()
+        .and_then(|items| items.first())
+        .and_then(|item| item["liveStreamingDetails"]["activeLiveChatId"].as_str())
+        .map(|s| s.to_string()))
+}
+
+/// Tails `live_chat_id`'s messages via `liveChat/messages` continuation polling, printing
+/// each text message as `{author, message, timestamp}`. Runs until the broadcast's chat
+/// has no more continuation token (the stream ended) or a request fails.
+async fn follow_live_chat(client: &reqwest::Client, api_key: &str, live_chat_id: &str, artist_name: &str) {
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut url = format!(
+            "https://www.googleapis.com/youtube/v3/liveChat/messages?liveChatId={live_chat_id}&part=snippet,authorDetails&key={api_key}"
+        );
+        if let Some(token) = &page_token {
+            url.push_str(&format!("&pageToken={token}"));
+        }
+
+        let response = match client.get(&url).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                info!("Live chat poll failed for {artist_name}: {e}");
+                return;
+            }
+        };
+        if !response.status().is_success() {
+            return;
+        }
+
+        let data: serde_json::Value = match response.json().await {
+            Ok(data) => data,
+            Err(_) => return,
+        };
+
+        for item in data["items"].as_array().cloned().unwrap_or_default() {
+            let Some(message) = item["snippet"]["textMessageDetails"]["messageText"].as_str() else { continue };
+            let author = item["authorDetails"]["displayName"].as_str().unwrap_or("unknown");
+            let timestamp = item["snippet"]["publishedAt"].as_str().unwrap_or("");
+
+            println!(
+                "    {} {}: {}",
+                format!("[{artist_name} chat]").bright_magenta(),
+                author.bright_white().bold(),
+                message
+            );
+            info!("{artist_name} chat - {author} ({timestamp}): {message}");
+        }
+
+        page_token = data["nextPageToken"].as_str().map(|s| s.to_string());
+        if page_token.is_none() {
+            return;
+        }
+
+        let polling_interval_ms = data["pollingIntervalMillis"].as_u64().unwrap_or(5000);
+        tokio::time::sleep(std::time::Duration::from_millis(polling_interval_ms)).await;
+    }
+}
+
+/// Polls `artists`' channels for live broadcasts every `watch_config.poll_interval_seconds`,
+/// printing a notification the first time each broadcast is seen. When
+/// `watch_config.follow_chat` is set, also spawns a background task tailing that
+/// broadcast's live chat. Runs until cancelled (Ctrl+C).
+pub async fn watch_for_live_streams(api_key: &str, artists: &[Artist], watch_config: &WatchConfig, parallel: usize) -> Result<()> {
+    let client = reqwest::Client::new();
+    let mut already_notified: HashSet<String> = HashSet::new();
+
+    loop {
+        let live: Vec<(Artist, String, String)> = stream::iter(artists.iter().cloned())
+            .map(|artist| {
+                let client = client.clone();
+                async move {
+                    match check_live(&client, api_key, &artist.channel_id).await {
+                        Ok(Some((video_id, title))) => Some((artist, video_id, title)),
+                        Ok(None) => None,
+                        Err(e) => {
+                            info!("Live check failed for {}: {e}", artist.name);
+                            None
+                        }
+                    }
+                }
+            })
+            .buffer_unordered(parallel.max(1))
+            .filter_map(|result| async move { result })
+            .collect()
+            .await;
+
+        for (artist, video_id, title) in live {
+            if already_notified.insert(video_id.clone()) {
+                println!(
+                    "{} {} {} {}",
+                    "LIVE".bright_red().bold(),
+                    artist.name.bright_white().bold(),
+                    title.bright_black(),
+                    format!("(https://www.youtube.com/watch?v={video_id})").bright_black()
+                );
+                info!("{} is live: {title}", artist.name);
+
+                if watch_config.follow_chat {
+                    let client = client.clone();
+                    let api_key = api_key.to_string();
+                    let artist_name = artist.name.clone();
+                    let video_id = video_id.clone();
+                    tokio::spawn(async move {
+                        match get_live_chat_id(&client, &api_key, &video_id).await {
+                            Ok(Some(live_chat_id)) => follow_live_chat(&client, &api_key, &live_chat_id, &artist_name).await,
+                            Ok(None) => info!("No live chat available for {artist_name}"),
+                            Err(e) => info!("Could not look up live chat for {artist_name}: {e}"),
+                        }
+                    });
+                }
+            }
+        }
+
+        tokio::time::sleep(std::time::Duration::from_secs(watch_config.poll_interval_seconds)).await;
+    }
+}
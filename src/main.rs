@@ -1,11 +1,22 @@
 use clap::{Parser, Subcommand};
+use futures::stream::{self, StreamExt};
 use log::{info, error, warn};
 use std::path::PathBuf;
 use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 use colored::*;
 
+mod backend;
+mod download;
+mod entry;
+mod export;
+mod feed;
+mod live;
+mod spotify;
+mod subscribe;
 mod youtube;
-use youtube::{YouTubeClient, parse_artists_file};
+use youtube::{YouTubeClient, parse_artists_file, Artist};
 
 fn format_subscriber_count(count: u64) -> String {
     use colored::*;
@@ -91,6 +102,20 @@ enum Commands {
         /// Ask for confirmation before making changes
         #[arg(long)]
         interactive: bool,
+
+        /// After each successful subscribe, fetch the channel's feed and print a digest
+        /// (title + latest upload) as a sanity check that it resolved correctly
+        #[arg(long)]
+        verify: bool,
+
+        /// How many artists to search+subscribe concurrently
+        #[arg(long, default_value_t = 4)]
+        parallel: usize,
+
+        /// Also unsubscribe from channels whose name is no longer in the target list
+        /// (opt-in; the default sync only ever adds subscriptions)
+        #[arg(long)]
+        prune: bool,
     },
     /// List current subscriptions
     List {
@@ -116,11 +141,145 @@ enum Commands {
     Goto {
         /// Subscription number to open
         number: usize,
-        
+
         /// Artists file path (optional, uses config.json if not specified)
         #[arg(long)]
         artists_file: Option<PathBuf>,
     },
+    /// Import artists from an external source
+    Import {
+        #[command(subcommand)]
+        source: ImportSource,
+    },
+    /// Watch subscribed channels for live streams
+    Watch {
+        /// Artists file path (optional, uses config.json if not specified)
+        #[arg(long)]
+        artists_file: Option<PathBuf>,
+
+        /// Seconds between polls (defaults to config.json's watch.poll_interval_seconds)
+        #[arg(long)]
+        interval: Option<u64>,
+    },
+    /// Download audio tracks for target or subscribed artists via yt-dlp
+    Download {
+        /// Artists file path (optional, uses subscribed/config artists if not specified)
+        #[arg(long)]
+        artists_file: Option<PathBuf>,
+
+        /// Extract audio only instead of downloading video
+        #[arg(long, default_value_t = true)]
+        audio_only: bool,
+
+        /// Audio format to request from yt-dlp
+        #[arg(long, value_enum, default_value_t = DownloadFormat::M4a)]
+        format: DownloadFormat,
+
+        /// Max tracks to download per artist
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+
+        /// How many artists to download concurrently
+        #[arg(long, default_value_t = 2)]
+        parallel: usize,
+
+        /// Where to write the download manifest JSON
+        #[arg(long, default_value = "downloads/manifest.json")]
+        manifest: PathBuf,
+    },
+    /// Resolve a pasted YouTube / YouTube Music URL directly to its channel and subscribe,
+    /// without a fuzzy name search
+    Add {
+        /// A channel, handle, custom-url, video, or playlist URL
+        url: String,
+
+        /// Artists file to record the resolved artist into
+        #[arg(long, default_value = "artists.txt")]
+        artists_file: PathBuf,
+
+        /// After subscribing, fetch the channel's feed and print a digest
+        #[arg(long)]
+        verify: bool,
+    },
+    /// Check subscribed channels' RSS feeds for uploads since the last run
+    Feed {
+        /// Only report uploads newer than this (e.g. "3d", "12h", "30m")
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Max new entries to report per channel
+        #[arg(long, default_value_t = 5)]
+        limit: usize,
+    },
+    /// Export subscribed/target artists' feeds as OPML and/or an aggregated RSS feed
+    Export {
+        /// Artists file path (optional, uses subscribed/config artists if not specified)
+        #[arg(long)]
+        artists_file: Option<PathBuf>,
+
+        /// Write an OPML outline of each channel's RSS feed to this path
+        #[arg(long)]
+        opml: Option<PathBuf>,
+
+        /// Write an aggregated RSS feed of recent uploads to this path
+        #[arg(long)]
+        rss: Option<PathBuf>,
+
+        /// Max entries (across all channels) in the aggregated RSS feed
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum DownloadFormat {
+    M4a,
+    Opus,
+}
+
+impl DownloadFormat {
+    fn as_ytdlp_format(self) -> &'static str {
+        match self {
+            DownloadFormat::M4a => "bestaudio[ext=m4a]/bestaudio",
+            DownloadFormat::Opus => "bestaudio[ext=webm]/bestaudio",
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum ImportSource {
+    /// Import followed artists and/or a playlist's artists from Spotify (requires API
+    /// credentials in config.json)
+    Spotify {
+        /// Artists file to merge the imported names into
+        #[arg(long, default_value = "artists.txt")]
+        artists_file: PathBuf,
+
+        /// Playlist URL or id to pull artists from, overriding config.json's
+        /// spotify.playlist_id
+        #[arg(long)]
+        playlist: Option<String>,
+
+        /// Confirm each imported name resolves to a YouTube Music channel, annotating
+        /// unmatched names in the artists file instead of silently adding them
+        #[arg(long)]
+        verify: bool,
+    },
+    /// Import artist names from an exported Spotify track listing (CSV or JSON), no API
+    /// credentials required
+    SpotifyExport {
+        /// Path to the exported CSV or JSON file
+        file: PathBuf,
+
+        /// Artists file to merge the imported names into
+        #[arg(long, default_value = "artists.txt")]
+        artists_file: PathBuf,
+
+        /// Confirm each imported name resolves to a YouTube Music channel, annotating
+        /// unmatched names in the artists file instead of silently adding them
+        #[arg(long)]
+        verify: bool,
+    },
 }
 
 #[tokio::main]
@@ -153,6 +312,9 @@ async fn main() -> anyhow::Result<()> {
             no_dry_run,
             delay,
             interactive,
+            verify,
+            parallel,
+            prune,
         } => {
             let actual_dry_run = if no_dry_run { false } else { dry_run };
             cmd_sync(
@@ -162,6 +324,9 @@ async fn main() -> anyhow::Result<()> {
                 interactive,
                 !cli.show_browser,
                 cli.verbose,
+                verify,
+                parallel,
+                prune,
             )
             .await
         }
@@ -174,6 +339,25 @@ async fn main() -> anyhow::Result<()> {
         Commands::Goto { number, artists_file } => {
             cmd_goto(number, artists_file.as_deref(), cli.verbose).await
         }
+        Commands::Watch { artists_file, interval } => {
+            cmd_watch(artists_file.as_deref(), interval, cli.verbose).await
+        }
+        Commands::Import { source } => match source {
+            ImportSource::Spotify { artists_file, playlist, verify } => {
+                cmd_import_spotify(&artists_file, playlist.as_deref(), verify).await
+            }
+            ImportSource::SpotifyExport { file, artists_file, verify } => {
+                cmd_import_spotify_export(&file, &artists_file, verify).await
+            }
+        },
+        Commands::Feed { since, limit } => cmd_feed(since.as_deref(), limit).await,
+        Commands::Add { url, artists_file, verify } => cmd_add(&url, &artists_file, verify).await,
+        Commands::Download { artists_file, audio_only, format, limit, parallel, manifest } => {
+            cmd_download(artists_file.as_deref(), audio_only, format, limit, parallel, &manifest).await
+        }
+        Commands::Export { artists_file, opml, rss, limit } => {
+            cmd_export(artists_file.as_deref(), opml.as_deref(), rss.as_deref(), limit).await
+        }
     };
 
     match result {
@@ -192,9 +376,12 @@ async fn cmd_sync(
     artists_file: Option<&std::path::Path>,
     dry_run: bool,
     delay: f64,
-    _interactive: bool,
+    interactive: bool,
     _headless: bool, // Not needed for API
-    _verbose: bool,
+    verbose: bool,
+    verify: bool,
+    parallel: usize,
+    prune: bool,
 ) -> anyhow::Result<()> {
     let source = if let Some(file) = artists_file {
         format!("file: {}", file.display())
@@ -249,6 +436,50 @@ async fn cmd_sync(
         }
     }
 
+    // Find subscriptions to remove (prune is strictly opt-in, so this stays empty otherwise).
+    // Target entries are the user's raw artists-file lines (often search queries like
+    // "tool"), while current_subscriptions carry resolved channel titles (e.g.
+    // "Tool - Topic"), which almost never match by display name. Resolve every target entry
+    // to its channel id first and diff on that instead, or a real --prune run would
+    // unsubscribe channels the user explicitly listed.
+    let to_unsubscribe: Vec<Artist> = if prune {
+        let rate_limiter = Arc::new(Mutex::new(tokio::time::interval(
+            std::time::Duration::from_secs_f64(delay.max(0.001)),
+        )));
+
+        let target_channel_ids: HashSet<String> = stream::iter(target_artists.iter().cloned())
+            .map(|target| {
+                let client = &client;
+                let rate_limiter = Arc::clone(&rate_limiter);
+                async move {
+                    rate_limiter.lock().await.tick().await;
+                    match client.resolve_artist_entry(&target, verbose).await {
+                        Ok(Some(artist)) => Some(artist.channel_id),
+                        Ok(None) => {
+                            warn!("Could not resolve target artist '{target}' while checking what to prune; leaving any matching subscription alone");
+                            None
+                        }
+                        Err(e) => {
+                            warn!("Failed to resolve target artist '{target}' while checking what to prune: {e}");
+                            None
+                        }
+                    }
+                }
+            })
+            .buffer_unordered(parallel.max(1))
+            .filter_map(|result| async move { result })
+            .collect()
+            .await;
+
+        current_subscriptions
+            .iter()
+            .filter(|a| !target_channel_ids.contains(&a.channel_id))
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     // Display sync plan
     println!("\n{}", "SYNC PLAN:".bright_cyan().bold());
     println!("{}", "==================================================".bright_cyan());
@@ -256,6 +487,9 @@ async fn cmd_sync(
     println!("Target artists: {}", target_artists.len().to_string().bright_white().bold());
     println!("Already subscribed: {}", already_subscribed.len().to_string().bright_green().bold());
     println!("To subscribe: {}", to_subscribe.len().to_string().bright_yellow().bold());
+    if prune {
+        println!("To unsubscribe: {}", to_unsubscribe.len().to_string().bright_red().bold());
+    }
 
     if !already_subscribed.is_empty() {
         println!("\n{}", "Already SUBSCRIBED to:".bright_green().bold());
@@ -264,6 +498,37 @@ async fn cmd_sync(
         }
     }
 
+    if !to_unsubscribe.is_empty() {
+        if dry_run {
+            println!("\n{}", "DRY RUN - Would UNSUBSCRIBE from:".bright_red().bold());
+            for artist in &to_unsubscribe {
+                println!("  {} {}", "-".bright_red().bold(), artist.name.bright_white());
+            }
+        } else {
+            println!("\n{}", "UNSUBSCRIBING from:".bright_red().bold());
+            for artist in &to_unsubscribe {
+                if interactive {
+                    print!("  {} {} {}", "Unsubscribe from".bright_red(), artist.name.bright_white().bold(), "? [y/N]:".bright_yellow());
+                    std::io::Write::flush(&mut std::io::stdout())?;
+                    let mut input = String::new();
+                    std::io::stdin().read_line(&mut input)?;
+                    if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
+                        println!("    {} skipped", "-".bright_black());
+                        continue;
+                    }
+                }
+
+                match client.unsubscribe_from_channel(&artist.channel_id).await {
+                    Ok(()) => println!("    {} {}", "✓".bright_green().bold(), "Unsubscribed".bright_green()),
+                    Err(e) => {
+                        warn!("Failed to unsubscribe from {}: {e}", artist.name);
+                        println!("    {} {}: {error}", "✗".bright_red().bold(), "Failed to unsubscribe".bright_red(), error = e.to_string().red());
+                    }
+                }
+            }
+        }
+    }
+
     if !to_subscribe.is_empty() {
         if dry_run {
             println!("\n{}", "DRY RUN - Would SUBSCRIBE to:".bright_yellow().bold());
@@ -271,37 +536,97 @@ async fn cmd_sync(
                 println!("  {} {}", "+".bright_yellow().bold(), artist.bright_white());
             }
         } else {
-            println!("\n{} {} {}", "SUBSCRIBING to".bright_blue().bold(), to_subscribe.len().to_string().bright_white().bold(), "artists:".bright_blue().bold());
-            
-            for (i, artist_name) in to_subscribe.iter().enumerate() {
-                println!("  {} {} {}", format!("[{current}/{total}]", current = i + 1, total = to_subscribe.len()).bright_black(), "Searching for:".bright_black(), artist_name.bright_white().bold());
-                
-                match client.search_artist(artist_name).await {
-                    Ok(Some(artist)) => {
-                        println!("    {} {} {}", "Found:".bright_green(), artist.name.bright_white().bold(), format!("({channel_id})", channel_id = artist.channel_id).bright_black());
-                        
-                        match client.subscribe_to_channel(&artist.channel_id).await {
-                            Ok(()) => println!("    {} {}", "✓".bright_green().bold(), "Successfully subscribed".bright_green()),
+            println!(
+                "\n{} {} {} {}",
+                "RESOLVING".bright_blue().bold(),
+                to_subscribe.len().to_string().bright_white().bold(),
+                "artists".bright_blue().bold(),
+                format!("({parallel} in parallel)").bright_black()
+            );
+
+            // Resolving names/links to channels used to run one artist at a time with a
+            // fixed sleep between each, which made large artist lists painfully slow. Fan
+            // the resolve step out with a bounded worker count instead, sharing one
+            // interval ticker so we still start new requests no faster than `delay` apart
+            // rather than hammering the API.
+            let total = to_subscribe.len();
+            let rate_limiter = Arc::new(Mutex::new(tokio::time::interval(
+                std::time::Duration::from_secs_f64(delay.max(0.001)),
+            )));
+
+            let resolved: Vec<(String, Option<Artist>)> = stream::iter(to_subscribe.iter().cloned().enumerate())
+                .map(|(i, artist_name)| {
+                    let client = &client;
+                    let rate_limiter = Arc::clone(&rate_limiter);
+                    async move {
+                        rate_limiter.lock().await.tick().await;
+                        println!("  {} {} {}", format!("[{current}/{total}]", current = i + 1).bright_black(), "Resolving:".bright_black(), artist_name.bright_white().bold());
+
+                        // Route through classify_entry/resolve_entry (entry.rs) rather
+                        // than search_artist directly, so a URL/@handle/video/playlist
+                        // line is pinned to its channel instead of being fuzzy-searched
+                        // as a literal query string.
+                        match client.resolve_artist_entry(&artist_name, verbose).await {
+                            Ok(Some(artist)) => {
+                                println!("    {} {} {}", "Found:".bright_green(), artist.name.bright_white().bold(), format!("({channel_id})", channel_id = artist.channel_id).bright_black());
+                                (artist_name, Some(artist))
+                            }
+                            Ok(None) => {
+                                warn!("Could not find artist: {artist_name}");
+                                println!("    {} {}", "✗".bright_red().bold(), "Artist not found".bright_red());
+                                (artist_name, None)
+                            }
                             Err(e) => {
-                                warn!("Failed to subscribe to {artist_name}: {e}");
-                                println!("    {} {}: {error}", "✗".bright_red().bold(), "Failed to subscribe".bright_red(), error = e.to_string().red());
+                                warn!("Search failed for {artist_name}: {e}");
+                                println!("    {} {}: {error}", "✗".bright_red().bold(), "Search error".bright_red(), error = e.to_string().red());
+                                (artist_name, None)
                             }
                         }
                     }
-                    Ok(None) => {
-                        warn!("Could not find artist: {artist_name}");
-                        println!("    {} {}", "✗".bright_red().bold(), "Artist not found".bright_red());
+                })
+                .buffer_unordered(parallel.max(1))
+                .collect()
+                .await;
+
+            let found: Vec<Artist> = resolved.iter().filter_map(|(_, artist)| artist.clone()).collect();
+            let channel_ids: Vec<String> = found.iter().map(|a| a.channel_id.clone()).collect();
+
+            println!(
+                "\n{} {} {} {}",
+                "SUBSCRIBING to".bright_blue().bold(),
+                found.len().to_string().bright_white().bold(),
+                "artists".bright_blue().bold(),
+                format!("({parallel} in parallel)").bright_black()
+            );
+
+            // Actually subscribing is handled by subscribe_to_channels_concurrent so a
+            // quota/rate-limit error on one worker widens the shared limiter for every
+            // other worker, not just its own retry loop.
+            let outcomes = client.subscribe_to_channels_concurrent(&channel_ids, parallel, delay).await;
+
+            let mut succeeded = 0;
+            for artist in &found {
+                let result = outcomes.iter().find(|(channel_id, _)| channel_id == &artist.channel_id).map(|(_, r)| r);
+                match result {
+                    Some(Ok(())) => {
+                        succeeded += 1;
+                        println!("    {} {} {}", "✓".bright_green().bold(), artist.name.bright_white().bold(), "subscribed".bright_green());
+                        if verify {
+                            match client.verify_subscription(&artist.channel_id).await {
+                                Ok(digest) => println!("    {} {}", "ℹ".bright_blue().bold(), digest.bright_black()),
+                                Err(e) => warn!("Could not verify subscription to {}: {e}", artist.name),
+                            }
+                        }
                     }
-                    Err(e) => {
-                        warn!("Search failed for {artist_name}: {e}");
-                        println!("    {} {}: {error}", "✗".bright_red().bold(), "Search error".bright_red(), error = e.to_string().red());
+                    Some(Err(e)) => {
+                        warn!("Failed to subscribe to {}: {e}", artist.name);
+                        println!("    {} {}: {error}", "✗".bright_red().bold(), artist.name.bright_white(), error = e.to_string().red());
                     }
-                }
-                
-                if i < to_subscribe.len() - 1 {
-                    tokio::time::sleep(std::time::Duration::from_secs_f64(delay)).await;
+                    None => warn!("No subscribe result for {}", artist.name),
                 }
             }
+
+            println!("\n{} {}/{} {}", "Subscribed to".bright_blue().bold(), succeeded, total, "artists".bright_blue());
         }
     } else {
         println!("\n{} {}", "✓".bright_green().bold(), "All target artists are already subscribed!".bright_green().bold());
@@ -447,6 +772,253 @@ async fn cmd_validate(artists_file: &PathBuf, verbose: bool) -> anyhow::Result<(
     }
 }
 
+async fn cmd_watch(
+    artists_file: Option<&std::path::Path>,
+    interval: Option<u64>,
+    verbose: bool,
+) -> anyhow::Result<()> {
+    let client = YouTubeClient::new().await?;
+    info!("Starting live-stream watcher (polling every {}s)", interval.unwrap_or(client.config().watch.poll_interval_seconds));
+
+    let (artists, _, total) = client.get_subscriptions_with_pagination(0, 1000, artists_file, false, verbose).await?;
+
+    println!(
+        "{} {} {}",
+        "Watching".bright_cyan().bold(),
+        total.to_string().bright_white().bold(),
+        "channel(s) for live streams. Press Ctrl+C to stop.".bright_cyan()
+    );
+
+    client.watch_live_streams(&artists, interval).await
+}
+
+async fn cmd_feed(since: Option<&str>, limit: usize) -> anyhow::Result<()> {
+    let since_secs = since.map(feed::parse_since).transpose()?;
+
+    info!("Checking subscribed channels for new uploads{}", since.map(|s| format!(" since {s}")).unwrap_or_default());
+
+    let client = YouTubeClient::new().await?;
+    let subscriptions = client.get_my_subscriptions().await?;
+
+    println!(
+        "{} {} {}",
+        "Checking".bright_cyan().bold(),
+        subscriptions.len().to_string().bright_white().bold(),
+        "subscribed channel(s) for new uploads...".bright_cyan()
+    );
+
+    client.check_feeds(&subscriptions, since_secs, limit).await
+}
+
+async fn cmd_download(
+    artists_file: Option<&std::path::Path>,
+    audio_only: bool,
+    format: DownloadFormat,
+    limit: usize,
+    parallel: usize,
+    manifest_path: &std::path::Path,
+) -> anyhow::Result<()> {
+    info!("Starting downloads (up to {limit} tracks/artist, {parallel} in parallel)");
+
+    let client = YouTubeClient::new().await?;
+    let (artists, _, _) = client.get_subscriptions_with_pagination(0, 1000, artists_file, false, false).await?;
+
+    let download_config = download::DownloadConfig {
+        audio_only,
+        format: format.as_ytdlp_format().to_string(),
+        track_limit: limit,
+        ..client.config().download.clone()
+    };
+
+    println!(
+        "\n{} {} {} {}",
+        "Downloading tracks for".bright_cyan().bold(),
+        artists.len().to_string().bright_white().bold(),
+        "artist(s)".bright_cyan().bold(),
+        format!("({parallel} in parallel)").bright_black()
+    );
+
+    let outcomes = client.download_artists_concurrent(&artists, &download_config, parallel).await;
+
+    let mut manifest = Vec::new();
+    let mut total_downloaded = 0;
+    for (artist, result) in outcomes {
+        match result {
+            Ok(tracks) => {
+                let succeeded = tracks.iter().filter(|t| t.success).count();
+                println!("  {} {} {}/{}", "✓".bright_green().bold(), artist.name.bright_white(), succeeded, tracks.len());
+                total_downloaded += succeeded;
+                manifest.push(download::DownloadManifestEntry {
+                    artist: artist.name.clone(),
+                    channel_id: artist.channel_id.clone(),
+                    tracks,
+                });
+            }
+            Err(e) => {
+                warn!("Failed to download tracks for {}: {e}", artist.name);
+                println!("  {} {}: {error}", "✗".bright_red().bold(), artist.name.bright_white(), error = e.to_string().red());
+            }
+        }
+    }
+
+    download::write_manifest(&manifest, manifest_path)?;
+
+    println!(
+        "\n{} {} {}",
+        "Downloaded".bright_green().bold(),
+        total_downloaded.to_string().bright_white().bold(),
+        format!("track(s); manifest written to {}", manifest_path.display()).bright_green()
+    );
+
+    Ok(())
+}
+
+async fn cmd_export(
+    artists_file: Option<&std::path::Path>,
+    opml_path: Option<&std::path::Path>,
+    rss_path: Option<&std::path::Path>,
+    limit: usize,
+) -> anyhow::Result<()> {
+    if opml_path.is_none() && rss_path.is_none() {
+        anyhow::bail!("Specify at least one of --opml <path> or --rss <path>");
+    }
+
+    let client = YouTubeClient::new().await?;
+    let (artists, _, _) = client.get_subscriptions_with_pagination(0, 1000, artists_file, false, false).await?;
+
+    if let Some(path) = opml_path {
+        client.export_opml(&artists, path)?;
+        println!("{} OPML to {}", "Wrote".bright_green().bold(), path.display());
+    }
+
+    if let Some(path) = rss_path {
+        client.export_rss(&artists, path, limit).await?;
+        println!("{} aggregated RSS feed to {}", "Wrote".bright_green().bold(), path.display());
+    }
+
+    Ok(())
+}
+
+async fn cmd_add(url: &str, artists_file: &std::path::Path, verify: bool) -> anyhow::Result<()> {
+    info!("Resolving pasted URL: {url}");
+
+    let client = YouTubeClient::new().await?;
+    let Some(artist) = client.resolve_url(url).await? else {
+        println!("{}", format!("Could not resolve '{url}' to a channel.").bright_red());
+        return Ok(());
+    };
+
+    client.subscribe_to_channel(&artist.channel_id).await?;
+    println!(
+        "{} {}",
+        "Subscribed to".bright_green().bold(),
+        artist.name.bright_white().bold()
+    );
+
+    if verify {
+        match client.verify_subscription(&artist.channel_id).await {
+            Ok(digest) => println!("  {}", digest.bright_black()),
+            Err(e) => warn!("Failed to verify subscription: {e}"),
+        }
+    }
+
+    let existing_content = std::fs::read_to_string(artists_file).unwrap_or_default();
+    let existing = parse_artists_file(&existing_content).unwrap_or_default();
+    if !existing.iter().any(|a| a.eq_ignore_ascii_case(&artist.name)) {
+        let mut lines: Vec<String> = existing_content.lines().map(|l| l.to_string()).collect();
+        lines.push(artist.name.clone());
+        std::fs::write(artists_file, lines.join("\n") + "\n")?;
+    }
+
+    Ok(())
+}
+
+async fn cmd_import_spotify(artists_file: &std::path::Path, playlist: Option<&str>, verify: bool) -> anyhow::Result<()> {
+    info!("Importing artists from Spotify");
+
+    let client = YouTubeClient::new().await?;
+    let imported = spotify::import_artists_from_spotify(client.config(), playlist).await?;
+    merge_imported_artists(&client, imported, artists_file, verify, "Spotify").await
+}
+
+async fn cmd_import_spotify_export(file: &std::path::Path, artists_file: &std::path::Path, verify: bool) -> anyhow::Result<()> {
+    info!("Importing artists from Spotify export {}", file.display());
+
+    let client = YouTubeClient::new().await?;
+    let imported = spotify::artists_from_export(file)?;
+    merge_imported_artists(&client, imported, artists_file, verify, "the export").await
+}
+
+/// Deduplicates `imported` names against `artists_file` and appends the new ones. When
+/// `verify` is set, each new name is resolved via `client.search_artist` first: matches are
+/// added as-is, and unmatched names are tagged `| unmatched` so the user can fix them
+/// before running `sync`.
+async fn merge_imported_artists(
+    client: &YouTubeClient,
+    imported: Vec<String>,
+    artists_file: &std::path::Path,
+    verify: bool,
+    source_label: &str,
+) -> anyhow::Result<()> {
+    let existing_content = std::fs::read_to_string(artists_file).unwrap_or_default();
+    let existing = parse_artists_file(&existing_content).unwrap_or_default();
+    let mut seen: HashSet<String> = existing.iter().map(|a| a.to_lowercase()).collect();
+
+    let mut lines: Vec<String> = existing_content.lines().map(|l| l.to_string()).collect();
+    let mut added = Vec::new();
+    let mut unmatched = Vec::new();
+
+    for name in imported {
+        if !seen.insert(name.to_lowercase()) {
+            continue;
+        }
+
+        if verify {
+            match client.search_artist(&name).await {
+                Ok(Some(_)) => lines.push(name.clone()),
+                Ok(None) => {
+                    lines.push(format!("{name} | unmatched"));
+                    unmatched.push(name.clone());
+                }
+                Err(e) => {
+                    warn!("Could not verify {name}: {e}");
+                    lines.push(format!("{name} | unmatched"));
+                    unmatched.push(name.clone());
+                }
+            }
+        } else {
+            lines.push(name.clone());
+        }
+        added.push(name);
+    }
+
+    std::fs::write(artists_file, lines.join("\n") + "\n")?;
+
+    println!(
+        "{} {} {}",
+        "Imported".bright_green().bold(),
+        added.len().to_string().bright_white().bold(),
+        format!("new artist(s) from {source_label}").bright_green()
+    );
+    for name in &added {
+        if unmatched.contains(name) {
+            println!("  {} {} {}", "?".bright_red().bold(), name.bright_white(), "(no YouTube Music match found)".bright_black());
+        } else {
+            println!("  {} {}", "+".bright_yellow().bold(), name.bright_white());
+        }
+    }
+    if added.is_empty() {
+        println!("{}", "No new artists to add (already present).".bright_black());
+    } else if !unmatched.is_empty() {
+        println!(
+            "{}",
+            format!("{} artist(s) need review before syncing (tagged `unmatched` in {}).", unmatched.len(), artists_file.display()).bright_yellow()
+        );
+    }
+
+    Ok(())
+}
+
 async fn cmd_goto(
     number: usize,
     artists_file: Option<&std::path::Path>,
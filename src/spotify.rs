@@ -0,0 +1,236 @@
+use crate::youtube::Config;
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+/// OAuth credentials for a Spotify app, used to bootstrap `config.artists` from a user's
+/// Spotify library rather than maintaining the list by hand.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SpotifyConfig {
+    #[serde(default)]
+    pub client_id: String,
+    #[serde(default)]
+    pub client_secret: String,
+    #[serde(default = "default_redirect_uri")]
+    pub redirect_uri: String,
+    /// Optional playlist to pull artist names from in addition to followed artists.
+    #[serde(default)]
+    pub playlist_id: Option<String>,
+}
+
+fn default_redirect_uri() -> String {
+    "http://localhost:8888/callback".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+/// Client-credentials grant: enough to read public data like a playlist's tracks, but not
+/// a user's own followed artists (those require the authorization-code flow below).
+async fn get_client_credentials_token(config: &SpotifyConfig) -> Result<String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post("https://accounts.spotify.com/api/token")
+        .basic_auth(&config.client_id, Some(&config.client_secret))
+        .form(&[("grant_type", "client_credentials")])
+        .send()
+        .await
+        .context("Failed to request Spotify client-credentials token")?;
+
+    let token: TokenResponse = response.json().await
+        .context("Failed to parse Spotify token response")?;
+    Ok(token.access_token)
+}
+
+/// Interactive authorization-code grant needed to read the user's own followed artists,
+/// mirroring the Google OAuth flow in `YouTubeClient::new_with_config`: print the consent
+/// URL, have the user paste back the redirected `code`, then exchange it for a token.
+async fn get_authorization_code_token(config: &SpotifyConfig) -> Result<String> {
+    let auth_url = format!(
+        "https://accounts.spotify.com/authorize?client_id={}&response_type=code&redirect_uri={}&scope=user-follow-read",
+        urlencoding::encode(&config.client_id),
+        urlencoding::encode(&config.redirect_uri),
+    );
+
+    println!("\n{}", "Spotify authorization required".to_string());
+    println!("1. Visit: {auth_url}");
+    println!("2. Approve access, then paste the `code` param from the redirected URL here:");
+
+    let mut code = String::new();
+    std::io::stdin().read_line(&mut code)?;
+    let code = code.trim().to_string();
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post("https://accounts.spotify.com/api/token")
+        .basic_auth(&config.client_id, Some(&config.client_secret))
+        .form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code.as_str()),
+            ("redirect_uri", config.redirect_uri.as_str()),
+        ])
+        .send()
+        .await
+        .context("Failed to exchange Spotify authorization code")?;
+
+    let token: TokenResponse = response.json().await
+        .context("Failed to parse Spotify token response")?;
+    Ok(token.access_token)
+}
+
+async fn followed_artist_names(token: &str) -> Result<Vec<String>> {
+    let client = reqwest::Client::new();
+    let mut names = Vec::new();
+    let mut after: Option<String> = None;
+
+    loop {
+        let mut url = "https://api.spotify.com/v1/me/following?type=artist&limit=50".to_string();
+        if let Some(cursor) = &after {
+            url.push_str(&format!("&after={cursor}"));
+        }
+
+        let response = client.get(&url).bearer_auth(token).send().await
+            .context("Failed to fetch followed artists from Spotify")?;
+        let data: serde_json::Value = response.json().await
+            .context("Failed to parse Spotify followed-artists response")?;
+
+        let artists = data["artists"]["items"].as_array().cloned().unwrap_or_default();
+        if artists.is_empty() {
+            break;
+        }
+
+        for artist in &artists {
+            if let Some(name) = artist["name"].as_str() {
+                names.push(name.to_string());
+            }
+        }
+
+        after = data["artists"]["cursors"]["after"].as_str().map(|s| s.to_string());
+        if after.is_none() {
+            break;
+        }
+    }
+
+    Ok(names)
+}
+
+async fn playlist_artist_names(token: &str, playlist_id: &str) -> Result<Vec<String>> {
+    let client = reqwest::Client::new();
+    let url = format!("https://api.spotify.com/v1/playlists/{playlist_id}/tracks");
+
+    let response = client.get(&url).bearer_auth(token).send().await
+        .with_context(|| format!("Failed to fetch tracks for playlist {playlist_id}"))?;
+    let data: serde_json::Value = response.json().await
+        .context("Failed to parse Spotify playlist-tracks response")?;
+
+    let mut names = Vec::new();
+    if let Some(items) = data["items"].as_array() {
+        for item in items {
+            if let Some(artists) = item["track"]["artists"].as_array() {
+                for artist in artists {
+                    if let Some(name) = artist["name"].as_str() {
+                        names.push(name.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(names)
+}
+
+/// Accepts either a bare playlist id or a playlist URL (e.g.
+/// `https://open.spotify.com/playlist/<id>?si=...`) and returns just the id.
+pub fn extract_playlist_id(raw: &str) -> String {
+    let raw = raw.trim();
+    match raw.split("playlist/").nth(1) {
+        Some(rest) => rest.split(['?', '&']).next().unwrap_or(rest).to_string(),
+        None => raw.to_string(),
+    }
+}
+
+/// Pulls the user's followed artists and, if `playlist` (or `config.spotify.playlist_id`)
+/// is set, that playlist's track artists, for the caller to merge into `config.artists` and
+/// resolve against YouTube via the existing search/subscribe pipeline.
+pub async fn import_artists_from_spotify(config: &Config, playlist: Option<&str>) -> Result<Vec<String>> {
+    let spotify = &config.spotify;
+    if spotify.client_id.is_empty() || spotify.client_secret.is_empty() {
+        anyhow::bail!("Spotify import requires `spotify.client_id` and `spotify.client_secret` in config.json");
+    }
+
+    let mut names = Vec::new();
+
+    match get_authorization_code_token(spotify).await {
+        Ok(token) => {
+            info!("Fetching followed artists from Spotify");
+            names.extend(followed_artist_names(&token).await?);
+        }
+        Err(e) => warn!("Could not authorize with Spotify for followed artists: {e}"),
+    }
+
+    let playlist_id = playlist.map(extract_playlist_id).or_else(|| spotify.playlist_id.clone());
+    if let Some(playlist_id) = playlist_id {
+        info!("Fetching playlist artists from Spotify playlist {playlist_id}");
+        let token = get_client_credentials_token(spotify).await?;
+        names.extend(playlist_artist_names(&token, &playlist_id).await?);
+    }
+
+    Ok(names)
+}
+
+/// Extracts distinct artist names from an exported Spotify track listing, for offline
+/// import without API credentials. Supports the common export shapes: a JSON array of
+/// `{"artists": [...]}` (strings or `{"name": ...}` objects) or `{"artist": "..."}`
+/// entries, and a CSV with an "Artist Name(s)" or "Artist" column (semicolon-separated
+/// when a track has multiple artists).
+pub fn artists_from_export(path: &std::path::Path) -> Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read Spotify export {}", path.display()))?;
+
+    let is_json = match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => ext.eq_ignore_ascii_case("json"),
+        None => false,
+    };
+
+    let mut names = Vec::new();
+    if is_json {
+        let tracks: Vec<serde_json::Value> = serde_json::from_str(&content)
+            .context("Failed to parse Spotify export JSON")?;
+        for track in &tracks {
+            if let Some(artists) = track["artists"].as_array() {
+                for artist in artists {
+                    if let Some(name) = artist.as_str() {
+                        names.push(name.to_string());
+                    } else if let Some(name) = artist["name"].as_str() {
+                        names.push(name.to_string());
+                    }
+                }
+            } else if let Some(name) = track["artist"].as_str() {
+                names.push(name.to_string());
+            }
+        }
+    } else {
+        let mut lines = content.lines();
+        let header = lines.next().context("Spotify export CSV is empty")?;
+        let columns: Vec<&str> = header.split(',').map(|c| c.trim()).collect();
+        let artist_col = columns.iter()
+            .position(|c| c.eq_ignore_ascii_case("Artist Name(s)") || c.eq_ignore_ascii_case("Artist"))
+            .context("Spotify export CSV has no 'Artist Name(s)' or 'Artist' column")?;
+
+        for line in lines {
+            let fields: Vec<&str> = line.split(',').collect();
+            if let Some(field) = fields.get(artist_col) {
+                for name in field.split(';') {
+                    let name = name.trim();
+                    if !name.is_empty() {
+                        names.push(name.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(names)
+}
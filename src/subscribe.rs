@@ -0,0 +1,218 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use google_youtube3::{api::Subscription, YouTube};
+#[cfg(test)]
+use mockall::automock;
+use log::{info, warn};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+type HttpsConnector = hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>;
+
+/// Shared across every worker in a `subscribe_to_channels_concurrent` batch: ticking it
+/// paces how often new subscribe attempts start, and `widen` lets any single worker that
+/// hits a quota/rate-limit error slow down *all* workers' future ticks, not just its own
+/// retry loop.
+#[derive(Clone)]
+pub struct SharedRateLimiter {
+    interval_secs: Arc<Mutex<f64>>,
+    ticker: Arc<Mutex<tokio::time::Interval>>,
+}
+
+impl SharedRateLimiter {
+    pub fn new(interval_secs: f64) -> Self {
+        let interval_secs = interval_secs.max(0.001);
+        Self {
+            interval_secs: Arc::new(Mutex::new(interval_secs)),
+            ticker: Arc::new(Mutex::new(tokio::time::interval(std::time::Duration::from_secs_f64(interval_secs)))),
+        }
+    }
+
+    /// Waits for the next tick at the current (possibly widened) interval.
+    pub async fn tick(&self) {
+        self.ticker.lock().await.tick().await;
+    }
+
+    /// Doubles the shared interval (capped at 60s) so every worker sharing this limiter
+    /// starts its next attempt later, not just the one that hit the error.
+    pub async fn widen(&self) {
+        let mut secs = self.interval_secs.lock().await;
+        *secs = (*secs * 2.0).min(60.0);
+        *self.ticker.lock().await = tokio::time::interval(std::time::Duration::from_secs_f64(*secs));
+        warn!("API quota/rate limit hit; widening shared subscribe interval to {:.1}s", *secs);
+    }
+}
+
+/// Classifies a raw API error message the same way `subscribe_with_retry` used to inline
+/// as string matching, so the real backend and mock-based tests share one mapping instead
+/// of each re-deriving it from `Display`ed `anyhow::Error`s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackendError {
+    QuotaExceeded,
+    Duplicate,
+    Forbidden,
+    NotFound,
+    ServerError(String),
+    Other(String),
+}
+
+impl BackendError {
+    pub fn classify(error_msg: &str) -> Self {
+        if error_msg.contains("quotaExceeded") || error_msg.contains("rateLimitExceeded") {
+            BackendError::QuotaExceeded
+        } else if error_msg.contains("subscriptionDuplicate") || error_msg.contains("already subscribed") {
+            BackendError::Duplicate
+        } else if error_msg.contains("forbidden") || error_msg.contains("403") {
+            BackendError::Forbidden
+        } else if error_msg.contains("channelNotFound") || error_msg.contains("404") {
+            BackendError::NotFound
+        } else if error_msg.contains("backend") || error_msg.contains("internal") {
+            BackendError::ServerError(error_msg.to_string())
+        } else {
+            BackendError::Other(error_msg.to_string())
+        }
+    }
+}
+
+/// Performs subscription mutations against the YouTube Data API, behind a trait so
+/// `subscribe_with_retry`'s backoff/error-classification logic can be unit-tested with a
+/// mock instead of live HTTP calls. Channel search has its own trait, `SearchBackend`
+/// (`backend.rs`), with subscriber-count-aware ranking that doesn't belong here.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait YoutubeBackend: Send + Sync {
+    async fn insert_subscription(&self, channel_id: &str) -> Result<(), BackendError>;
+}
+
+/// The real `YoutubeBackend`, backed by the official API client.
+pub struct RealYoutubeBackend {
+    pub youtube: YouTube<HttpsConnector>,
+}
+
+#[async_trait]
+impl YoutubeBackend for RealYoutubeBackend {
+    async fn insert_subscription(&self, channel_id: &str) -> Result<(), BackendError> {
+        let subscription = Subscription {
+            snippet: Some(google_youtube3::api::SubscriptionSnippet {
+                resource_id: Some(google_youtube3::api::ResourceId {
+                    channel_id: Some(channel_id.to_string()),
+                    kind: Some("youtube#channel".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let req = self.youtube.subscriptions().insert(subscription).add_part("snippet");
+        req.doit().await
+            .map(|_| ())
+            .map_err(|e| BackendError::classify(&e.to_string()))
+    }
+}
+
+/// Subscribes to `channel_id`, retrying on transient failures: exponential backoff on
+/// quota/rate-limit errors, linear backoff on server errors, a duplicate-subscription
+/// error treated as success, and forbidden/not-found errors failing immediately with no
+/// retry. When `shared_limiter` is set (batched concurrent subscribes), a quota/rate-limit
+/// error also widens it, slowing down every other in-flight worker, not just this retry
+/// loop's own backoff.
+pub async fn subscribe_with_retry(
+    backend: &dyn YoutubeBackend,
+    channel_id: &str,
+    max_retries: u32,
+    shared_limiter: Option<&SharedRateLimiter>,
+) -> Result<()> {
+    info!("Subscribing to channel: {channel_id}");
+
+    for attempt in 0..max_retries {
+        match backend.insert_subscription(channel_id).await {
+            Ok(()) => {
+                info!("Successfully subscribed to channel: {channel_id}");
+                return Ok(());
+            }
+            Err(BackendError::Duplicate) => {
+                info!("Already subscribed to channel: {channel_id}");
+                return Ok(());
+            }
+            Err(BackendError::QuotaExceeded) => {
+                if let Some(limiter) = shared_limiter {
+                    limiter.widen().await;
+                }
+                if attempt < max_retries - 1 {
+                    let delay = 2_u64.pow(attempt) * 1000;
+                    warn!("API quota/rate limit hit, retrying in {delay}ms");
+                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                    continue;
+                }
+                anyhow::bail!("API quota exceeded after {max_retries} retries. Please wait and try again later, or request quota increase in Google Cloud Console")
+            }
+            Err(BackendError::ServerError(msg)) => {
+                if attempt < max_retries - 1 {
+                    let delay = 1000 + (attempt as u64 * 500);
+                    warn!("Server error, retrying in {delay}ms");
+                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                    continue;
+                }
+                anyhow::bail!("Server error after {max_retries} retries: {msg}")
+            }
+            Err(BackendError::Forbidden) => {
+                anyhow::bail!("Permission denied. Check OAuth consent screen settings and ensure your account is added as a test user")
+            }
+            Err(BackendError::NotFound) => {
+                anyhow::bail!("Channel not found or no longer available")
+            }
+            Err(BackendError::Other(msg)) => {
+                anyhow::bail!("Subscription failed: {msg}")
+            }
+        }
+    }
+
+    anyhow::bail!("Failed to subscribe after {max_retries} attempts")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn retries_on_quota_exceeded_then_succeeds() {
+        let mut mock = MockYoutubeBackend::new();
+        let mut call = 0;
+        mock.expect_insert_subscription()
+            .times(2)
+            .returning(move |_| {
+                call += 1;
+                if call == 1 {
+                    Err(BackendError::QuotaExceeded)
+                } else {
+                    Ok(())
+                }
+            });
+
+        let result = subscribe_with_retry(&mock, "UCabc123", 3, None).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn duplicate_subscription_is_treated_as_success() {
+        let mut mock = MockYoutubeBackend::new();
+        mock.expect_insert_subscription()
+            .times(1)
+            .returning(|_| Err(BackendError::Duplicate));
+
+        let result = subscribe_with_retry(&mock, "UCabc123", 3, None).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn forbidden_error_is_not_retried() {
+        let mut mock = MockYoutubeBackend::new();
+        mock.expect_insert_subscription()
+            .times(1)
+            .returning(|_| Err(BackendError::Forbidden));
+
+        let result = subscribe_with_retry(&mock, "UCabc123", 3, None).await;
+        assert!(result.is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{Result, Context};
-use google_youtube3::{YouTube, api::Subscription};
+use google_youtube3::YouTube;
 use hyper_rustls::{HttpsConnectorBuilder};
 use hyper_util::{client::legacy::Client, rt::TokioExecutor};
 use log::{info, warn};
@@ -7,6 +7,10 @@ use serde::{Deserialize, Serialize};
 use google_youtube3::yup_oauth2::{self as oauth2, InstalledFlowAuthenticator, InstalledFlowReturnMethod};
 use colored::*;
 use rusqlite::{Connection, params};
+use futures::stream::{self, StreamExt};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::entry;
 // use chrono::{DateTime, Utc, Duration}; // For future cache expiry features
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +43,14 @@ pub struct SettingsConfig {
     pub token_cache_file: String,
     pub max_subscription_retries: u32,
     pub continue_on_subscription_failure: bool,
+    #[serde(default = "default_parallel")]
+    pub parallel: usize,
+    #[serde(default)]
+    pub backend: crate::backend::BackendKind,
+}
+
+fn default_parallel() -> usize {
+    4
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,10 +59,18 @@ pub struct Config {
     pub database: DatabaseConfig,
     pub artists: Vec<String>,
     pub settings: SettingsConfig,
+    #[serde(default)]
+    pub download: crate::download::DownloadConfig,
+    #[serde(default)]
+    pub spotify: crate::spotify::SpotifyConfig,
+    #[serde(default)]
+    pub watch: crate::live::WatchConfig,
 }
 
 pub struct YouTubeClient {
     youtube: YouTube<hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>>,
+    backend: Box<dyn crate::backend::SearchBackend>,
+    sub_backend: Box<dyn crate::subscribe::YoutubeBackend>,
     config: Config,
 }
 
@@ -158,11 +178,22 @@ impl YouTubeClient {
             .build(https);
 
         let youtube = YouTube::new(client, auth);
-        
+
         info!("API key available for public operations");
-        
-        let client = Self { 
+
+        let backend = crate::backend::build_backend(
+            config.settings.backend,
+            youtube.clone(),
+            config.google.api_key.clone(),
+        );
+        info!("Using {:?} search backend", config.settings.backend);
+
+        let sub_backend = Box::new(crate::subscribe::RealYoutubeBackend { youtube: youtube.clone() });
+
+        let client = Self {
             youtube,
+            backend,
+            sub_backend,
             config,
         };
         
@@ -176,6 +207,10 @@ impl YouTubeClient {
         &self.config.artists
     }
 
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     fn init_cache_db(&self) -> Result<()> {
         let conn = Connection::open(&self.config.database.cache_db_path)?;
         
@@ -191,6 +226,9 @@ impl YouTubeClient {
             [],
         )?;
         
+        crate::download::init_downloads_table(&conn)?;
+        crate::feed::init_feed_state_table(&conn)?;
+
         info!("Initialized artist cache database: {}", self.config.database.cache_db_path);
         Ok(())
     }
@@ -259,62 +297,88 @@ impl YouTubeClient {
     }
 
     async fn get_channel_details(&self, channel_id: &str) -> Result<Artist> {
-        // Try API key approach first (more quota-friendly)
-        let api_key = &self.config.google.api_key;
-        if !api_key.is_empty() {
-            let client = reqwest::Client::new();
-            let url = format!(
-                "https://www.googleapis.com/youtube/v3/channels?part=snippet,statistics&id={channel_id}&key={api_key}"
-            );
-            
-            if let Ok(response) = client.get(&url).send().await {
-                if let Ok(data) = response.json::<serde_json::Value>().await {
-                    if let Some(items) = data["items"].as_array() {
-                        if let Some(item) = items.first() {
-                            let name = item["snippet"]["title"].as_str().unwrap_or("Unknown").to_string();
-                            let description = item["snippet"]["description"].as_str().map(|s| s.to_string());
-                            let subscriber_count = item["statistics"]["subscriberCount"].as_str()
-                                .and_then(|s| s.parse::<u64>().ok());
-                            
-                            return Ok(Artist {
-                                name,
-                                channel_id: channel_id.to_string(),
-                                subscriber_count,
-                                description,
-                            });
-                        }
-                    }
-                }
-            }
-        }
-        
-        // Fallback to OAuth approach
+        self.backend.channel_details(channel_id).await
+    }
+
+    /// Resolves a channel via the API using a single `forHandle`/`forUsername` lookup
+    /// parameter, for the handle and legacy-custom-URL cases below.
+    async fn get_channel_by_param(&self, param: &str, value: &str) -> Result<Option<Artist>> {
         let req = self.youtube.channels()
             .list(&vec!["snippet".to_string(), "statistics".to_string()])
-            .add_id(channel_id);
-            
-        let response = req.doit().await?;
-        let (_, channel_response) = response;
-        
-        if let Some(items) = channel_response.items {
-            if let Some(channel) = items.first() {
-                if let Some(snippet) = &channel.snippet {
-                    let name = snippet.title.as_ref().unwrap_or(&"Unknown".to_string()).clone();
-                    let description = snippet.description.clone();
-                    let subscriber_count = channel.statistics.as_ref()
-                        .and_then(|s| s.subscriber_count);
-                    
-                    return Ok(Artist {
-                        name,
-                        channel_id: channel_id.to_string(),
-                        subscriber_count,
-                        description,
-                    });
-                }
+            .param(param, value);
+
+        let (_, response) = req.doit().await?;
+        let Some(channel) = response.items.and_then(|items| items.into_iter().next()) else {
+            return Ok(None);
+        };
+        let Some(snippet) = channel.snippet else { return Ok(None) };
+
+        Ok(Some(Artist {
+            name: snippet.title.unwrap_or_else(|| "Unknown".to_string()),
+            channel_id: channel.id.unwrap_or_default(),
+            subscriber_count: channel.statistics.and_then(|s| s.subscriber_count),
+            description: snippet.description,
+        }))
+    }
+
+    /// Resolves an artists-file entry that is a direct YouTube link (channel URL, @handle,
+    /// legacy `/c/`/`/user/` URL, or a video/playlist to resolve to its owning channel)
+    /// straight to the channel it names, instead of going through fuzzy name search.
+    async fn resolve_entry(&self, entry: &entry::ArtistEntry, original_name: &str, verbose: bool) -> Result<Option<Artist>> {
+        use entry::ArtistEntry;
+
+        match entry {
+            ArtistEntry::Name(name) => self.search_artist_with_verbose(name, verbose).await,
+            ArtistEntry::ChannelId(channel_id) => Ok(Some(self.get_channel_details(channel_id).await?)),
+            ArtistEntry::Handle(handle) => self.get_channel_by_param("forHandle", handle).await,
+            ArtistEntry::CustomUrl(name) => self.get_channel_by_param("forUsername", name).await,
+            ArtistEntry::Video(video_id) => {
+                let req = self.youtube.videos().list(&vec!["snippet".to_string()]).add_id(video_id);
+                let (_, response) = req.doit().await?;
+                let Some(video) = response.items.and_then(|items| items.into_iter().next()) else {
+                    warn!("Could not resolve video link for {original_name}: video not found");
+                    return Ok(None);
+                };
+                let Some(channel_id) = video.snippet.and_then(|s| s.channel_id) else {
+                    return Ok(None);
+                };
+                Ok(Some(self.get_channel_details(&channel_id).await?))
+            }
+            ArtistEntry::Playlist(playlist_id) => {
+                let req = self.youtube.playlists().list(&vec!["snippet".to_string()]).add_id(playlist_id);
+                let (_, response) = req.doit().await?;
+                let Some(playlist) = response.items.and_then(|items| items.into_iter().next()) else {
+                    warn!("Could not resolve playlist link for {original_name}: playlist not found");
+                    return Ok(None);
+                };
+                let Some(channel_id) = playlist.snippet.and_then(|s| s.channel_id) else {
+                    return Ok(None);
+                };
+                Ok(Some(self.get_channel_details(&channel_id).await?))
             }
         }
-        
-        anyhow::bail!("Failed to get channel details for {channel_id}")
+    }
+
+    /// Resolves a pasted YouTube/YouTube Music URL directly to its owning channel, without
+    /// a fuzzy name search: classifies the URL into a channel/handle/custom-url/video/
+    /// playlist target (`entry::classify_entry`) and dispatches it through the same
+    /// `resolve_entry` lookup the artists-file importer uses. Returns `Ok(None)` if `url`
+    /// doesn't look like a URL at all, or if the target it names can't be found.
+    pub async fn resolve_url(&self, url: &str) -> Result<Option<Artist>> {
+        let entry = entry::classify_entry(url);
+        if let entry::ArtistEntry::Name(_) = entry {
+            anyhow::bail!("'{url}' doesn't look like a YouTube URL");
+        }
+        self.resolve_entry(&entry, url, false).await
+    }
+
+    /// Resolves one artists-file-style entry (a plain name or a direct link) the same way
+    /// `get_subscriptions_with_pagination` resolves each line of a whole file: classifies
+    /// it via `entry::classify_entry`, then dispatches to direct channel lookup for links
+    /// or fuzzy search for plain names. Unlike `resolve_url`, a plain name is not an error.
+    pub async fn resolve_artist_entry(&self, raw: &str, verbose: bool) -> Result<Option<Artist>> {
+        let classified = entry::classify_entry(raw);
+        self.resolve_entry(&classified, raw, verbose).await
     }
 
     pub async fn get_subscriptions_with_pagination(&self, offset: usize, limit: usize, artists_file: Option<&std::path::Path>, force_update: bool, verbose: bool) -> Result<(Vec<Artist>, bool, usize)> {
@@ -357,115 +421,140 @@ impl YouTubeClient {
         }
         
         let has_more = offset + page_channels.len() < total_channels;
-        let mut artists = Vec::new();
-        
+        let parallel = self.config.settings.parallel.max(1);
+
         use colored::*;
         if verbose {
-            println!("{}", format!("Fetching details for {page_channels_len} channels (page {page_num} of approx {total_pages})...", 
-                     page_channels_len = page_channels.len(), 
+            println!("{}", format!("Fetching details for {page_channels_len} channels (page {page_num} of approx {total_pages}, {parallel} in parallel)...",
+                     page_channels_len = page_channels.len(),
                      page_num = (offset / limit) + 1,
-                     total_pages = (total_channels + limit - 1) / limit).bright_black());
+                     total_pages = (total_channels + limit - 1) / limit,
+                     parallel = parallel).bright_black());
         }
-        info!("Fetching real details for {} channels (page {} of approx {})", 
-              page_channels.len(), 
+        info!("Fetching real details for {} channels (page {} of approx {}, {} in parallel)",
+              page_channels.len(),
               (offset / limit) + 1,
-              (total_channels + limit - 1) / limit);
-        
-        for (i, channel_name) in page_channels.iter().enumerate() {
-            if verbose {
-                print!("{}", format!("  [{current}/{total}] {channel_name}...", current = i + 1, total = page_channels.len(), channel_name = channel_name).bright_black());
-                std::io::Write::flush(&mut std::io::stdout()).unwrap();
-            }
-            info!("Processing channel: {channel_name}");
-            
-            // Check cache first (unless force_update is true)
-            if !force_update {
-                match self.get_cached_artist(channel_name) {
-                    Ok(Some(cached_artist)) => {
-                        if verbose {
-                            println!(" cached ✓");
-                        }
-                        info!("Using cached data for: {channel_name}");
-                        artists.push(cached_artist);
-                        continue;
-                    }
-                    Ok(None) => {
-                        info!("No cache for {channel_name}, searching API...");
-                    }
-                    Err(e) => {
-                        warn!("Cache error for {channel_name}: {e}");
-                    }
-                }
-            } else {
-                info!("Force update enabled, bypassing cache for: {channel_name}");
-            }
-            
-            // Search for the channel to get its ID with timeout
-            let search_timeout = tokio::time::timeout(
-                std::time::Duration::from_secs(self.config.settings.search_timeout_seconds),
-                self.search_artist_with_verbose(channel_name, verbose)
-            ).await;
-
-            match search_timeout {
-                Ok(search_result) => match search_result {
-                    Ok(Some(artist)) => {
-                        // Now get full details including subscriber count
-                        match self.get_channel_details(&artist.channel_id).await {
-                            Ok(detailed_artist) => {
-                                if verbose {
-                                    println!(" found ✓");
-                                }
-                                info!("Got details for {}: {} subs", detailed_artist.name, 
-                                    detailed_artist.subscriber_count.map(|c| c.to_string()).unwrap_or("N/A".to_string()));
-                                
-                                // Cache the detailed artist data
-                                if let Err(e) = self.cache_artist(channel_name, &detailed_artist) {
-                                    warn!("Failed to cache {channel_name}: {e}");
-                                }
-                                
-                                artists.push(detailed_artist);
-                            },
-                            Err(e) => {
+              (total_channels + limit - 1) / limit,
+              parallel);
+
+        // Resolving channels one at a time made a few hundred artists take minutes, so we
+        // fan them out with a bounded worker count. Each task opens its own sqlite connection
+        // (rusqlite handles aren't shareable across an `&self` borrow) and shares a single
+        // interval ticker so we never exceed `parallel` in-flight requests or start new ones
+        // faster than `search_delay_ms` apart, preserving the original rate limit.
+        let rate_limiter = Arc::new(Mutex::new(tokio::time::interval(
+            std::time::Duration::from_millis(self.config.settings.search_delay_ms.max(1)),
+        )));
+        let total_in_page = page_channels.len();
+
+        let mut resolved: Vec<(usize, Option<Artist>)> = stream::iter(page_channels.into_iter().enumerate())
+            .map(|(i, channel_name)| {
+                let rate_limiter = Arc::clone(&rate_limiter);
+                async move {
+                    info!("Processing channel: {channel_name}");
+
+                    // Check cache first (unless force_update is true) - cheap, no rate limit needed
+                    if !force_update {
+                        match self.get_cached_artist(&channel_name) {
+                            Ok(Some(cached_artist)) => {
                                 if verbose {
-                                    println!(" partial ⚠");
-                                }
-                                info!("Failed to get details for {channel_name}: {e}");
-                                
-                                // Cache basic info so we don't search again
-                                if let Err(cache_err) = self.cache_artist(channel_name, &artist) {
-                                    warn!("Failed to cache basic info for {channel_name}: {cache_err}");
+                                    println!("{}", format!("  [{current}/{total}] {channel_name}... cached ✓", current = i + 1, total = total_in_page, channel_name = channel_name).bright_black());
                                 }
-                                
-                                artists.push(artist); // Use basic info
+                                info!("Using cached data for: {channel_name}");
+                                return (i, Some(cached_artist));
                             }
+                            Ok(None) => info!("No cache for {channel_name}, searching API..."),
+                            Err(e) => warn!("Cache error for {channel_name}: {e}"),
                         }
-                    },
-                    Ok(None) => {
-                        if verbose {
-                            println!(" not found ✗");
+                    } else {
+                        info!("Force update enabled, bypassing cache for: {channel_name}");
+                    }
+
+                    rate_limiter.lock().await.tick().await;
+                    let classified = entry::classify_entry(&channel_name);
+                    let search_timeout = tokio::time::timeout(
+                        std::time::Duration::from_secs(self.config.settings.search_timeout_seconds),
+                        self.resolve_entry(&classified, &channel_name, verbose)
+                    ).await;
+
+                    let found = match search_timeout {
+                        Ok(Ok(Some(artist))) => artist,
+                        Ok(Ok(None)) => {
+                            if verbose {
+                                println!("{}", format!("  [{current}/{total}] {channel_name}... not found ✗", current = i + 1, total = total_in_page, channel_name = channel_name).bright_black());
+                            }
+                            info!("Could not find channel: {channel_name}");
+                            return (i, None);
+                        }
+                        Ok(Err(e)) => {
+                            if verbose {
+                                println!("{}", format!("  [{current}/{total}] {channel_name}... error ✗", current = i + 1, total = total_in_page, channel_name = channel_name).bright_black());
+                            }
+                            info!("Search failed for {channel_name}: {e}");
+                            return (i, None);
+                        }
+                        Err(_) => {
+                            if verbose {
+                                println!("{}", format!("  [{current}/{total}] {channel_name}... too long ⏱", current = i + 1, total = total_in_page, channel_name = channel_name).bright_red());
+                            }
+                            info!("Search timeout for {channel_name} (> {} seconds)", self.config.settings.search_timeout_seconds);
+                            return (i, None);
+                        }
+                    };
+
+                    // Direct-link entries (channel/handle/video/playlist) already come back
+                    // from `resolve_entry` with full details; only a name match from search
+                    // still needs a details lookup for the subscriber count.
+                    if !matches!(classified, entry::ArtistEntry::Name(_)) {
+                        if let Err(e) = self.cache_artist(&channel_name, &found) {
+                            warn!("Failed to cache {channel_name}: {e}");
                         }
-                        info!("Could not find channel: {channel_name}");
-                    },
-                    Err(e) => {
                         if verbose {
-                            println!(" error ✗");
+                            println!("{}", format!("  [{current}/{total}] {channel_name}... found ✓", current = i + 1, total = total_in_page, channel_name = channel_name).bright_black());
                         }
-                        info!("Search failed for {channel_name}: {e}");
+                        return (i, Some(found));
                     }
-                },
-                Err(_) => {
-                    if verbose {
-                        use colored::*;
-                        println!(" {}", "too long ⏱".bright_red());
+
+                    // Get full details including subscriber count
+                    rate_limiter.lock().await.tick().await;
+                    match self.get_channel_details(&found.channel_id).await {
+                        Ok(detailed_artist) => {
+                            if verbose {
+                                println!("{}", format!("  [{current}/{total}] {channel_name}... found ✓", current = i + 1, total = total_in_page, channel_name = channel_name).bright_black());
+                            }
+                            info!("Got details for {}: {} subs", detailed_artist.name,
+                                detailed_artist.subscriber_count.map(|c| c.to_string()).unwrap_or("N/A".to_string()));
+
+                            if let Err(e) = self.cache_artist(&channel_name, &detailed_artist) {
+                                warn!("Failed to cache {channel_name}: {e}");
+                            }
+
+                            (i, Some(detailed_artist))
+                        },
+                        Err(e) => {
+                            if verbose {
+                                println!("{}", format!("  [{current}/{total}] {channel_name}... partial ⚠", current = i + 1, total = total_in_page, channel_name = channel_name).bright_black());
+                            }
+                            info!("Failed to get details for {channel_name}: {e}");
+
+                            if let Err(cache_err) = self.cache_artist(&channel_name, &found) {
+                                warn!("Failed to cache basic info for {channel_name}: {cache_err}");
+                            }
+
+                            (i, Some(found))
+                        }
                     }
-                    info!("Search timeout for {channel_name} (> {} seconds)", self.config.settings.search_timeout_seconds);
                 }
-            }
-            
-            // Add delay between requests to be respectful
-            tokio::time::sleep(std::time::Duration::from_millis(self.config.settings.search_delay_ms)).await;
-        }
-        
+            })
+            .buffer_unordered(parallel)
+            .collect()
+            .await;
+
+        // buffer_unordered completes tasks out of order; restore the original input order
+        // so callers see deterministic results regardless of which worker finished first.
+        resolved.sort_by_key(|(i, _)| *i);
+        let artists: Vec<Artist> = resolved.into_iter().filter_map(|(_, artist)| artist).collect();
+
         if artists.is_empty() {
             let mock_subs = self.get_mock_subscriptions().await?;
             let len = mock_subs.len();
@@ -562,187 +651,198 @@ impl YouTubeClient {
         Ok(None)
     }
 
-    async fn try_search_with_term(&self, search_term: &str, original_name: &str) -> Result<Option<Artist>> {
-        // Try using API key for search operations
-        let api_key = &self.config.google.api_key;
-        if !api_key.is_empty() {
-            let client = reqwest::Client::new();
-            let url = format!(
-                "https://www.googleapis.com/youtube/v3/search?part=snippet&q={}&type=channel&maxResults=10&key={}",
-                urlencoding::encode(search_term),
-                api_key
-            );
+    async fn try_search_with_term(&self, search_term: &str, _original_name: &str) -> Result<Option<Artist>> {
+        Ok(self.backend.search_channel(search_term).await)
+    }
 
-            let response = client.get(&url).send().await
-                .context("Failed to make API request")?;
-            
-            if response.status().is_success() {
-                let search_result: serde_json::Value = response.json().await
-                    .context("Failed to parse API response")?;
-                
-                return self.parse_api_search_results(search_result, original_name);
-            } else {
-                info!("API key search failed with status: {}", response.status());
-                // Fall through to OAuth approach
+    /// Subscribes to `channel_id`, retrying on transient failures. The retry/backoff
+    /// policy and error classification live in `crate::subscribe` behind the
+    /// `YoutubeBackend` trait, so they can be unit-tested with a mock backend.
+    pub async fn subscribe_to_channel(&self, channel_id: &str) -> Result<()> {
+        crate::subscribe::subscribe_with_retry(
+            self.sub_backend.as_ref(),
+            channel_id,
+            self.config.settings.max_subscription_retries,
+            None,
+        ).await
+    }
+
+    /// Archives up to `download_config.track_limit` of `artist`'s uploads via `yt-dlp`,
+    /// skipping tracks already recorded as downloaded in the `downloads` table. Takes an
+    /// explicit config (rather than always `self.config.download`) so callers like
+    /// `download_artists_concurrent` can apply one-off CLI overrides.
+    pub async fn download_artist(&self, artist: &Artist, download_config: &crate::download::DownloadConfig) -> Result<Vec<crate::download::DownloadResult>> {
+        info!("Enumerating uploads for {} ({})", artist.name, artist.channel_id);
+
+        let uploads = crate::download::list_channel_uploads(
+            &download_config.binary,
+            &artist.channel_id,
+            download_config.track_limit,
+        ).await?;
+
+        let conn = Connection::open(&self.config.database.cache_db_path)?;
+        let mut results = Vec::new();
+
+        for entry in uploads {
+            if crate::download::already_downloaded(&conn, &entry.id)? {
+                info!("Already downloaded: {} ({})", entry.title, entry.id);
+                continue;
             }
-        }
 
-        // Fallback to OAuth approach
-        let req = self.youtube.search().list(&vec!["snippet".to_string()])
-            .q(search_term)
-            .param("type", "channel")
-            .max_results(10);
-
-        let response = req.doit().await
-            .context(format!("Failed to search for artist '{search_term}'. This might indicate: 1) YouTube Data API v3 is not enabled, 2) Missing search permissions, or 3) API quota exceeded"))?;
-
-        let (_, search_response) = response;
-        self.parse_search_results(search_response, original_name)
-    }
-
-    fn parse_search_results(&self, search_response: google_youtube3::api::SearchListResponse, artist_name: &str) -> Result<Option<Artist>> {
-        if let Some(items) = search_response.items {
-            for item in items {
-                if let Some(snippet) = item.snippet {
-                    if let Some(title) = &snippet.title {
-                        // Simple matching - look for exact or close match
-                        if title.to_lowercase() == artist_name.to_lowercase() ||
-                           title.to_lowercase().contains(&artist_name.to_lowercase()) ||
-                           artist_name.to_lowercase().contains(&title.to_lowercase()) {
-                            
-                            let channel_id = item.id.as_ref()
-                                .and_then(|id| id.channel_id.as_ref())
-                                .unwrap_or(&snippet.channel_id.unwrap_or_default())
-                                .clone();
-
-                            let artist = Artist {
-                                name: title.clone(),
-                                channel_id,
-                                subscriber_count: None,
-                                description: snippet.description,
-                            };
-
-                            info!("Found matching artist: {}", artist.name);
-                            return Ok(Some(artist));
-                        }
+            info!("Downloading: {} ({})", entry.title, entry.id);
+            let result = match crate::download::download_track(download_config, &entry.id).await {
+                Ok(path) => crate::download::DownloadResult {
+                    track_id: entry.id.clone(),
+                    title: entry.title.clone(),
+                    path: Some(path),
+                    success: true,
+                },
+                Err(e) => {
+                    warn!("Failed to download {} ({}): {e}", entry.title, entry.id);
+                    crate::download::DownloadResult {
+                        track_id: entry.id.clone(),
+                        title: entry.title.clone(),
+                        path: None,
+                        success: false,
                     }
                 }
-            }
+            };
+
+            crate::download::record_download(&conn, &artist.channel_id, &result)?;
+            results.push(result);
         }
 
-        warn!("No matching artist found for: {artist_name}");
-        Ok(None)
+        Ok(results)
     }
 
-    fn parse_api_search_results(&self, search_result: serde_json::Value, artist_name: &str) -> Result<Option<Artist>> {
-        if let Some(items) = search_result["items"].as_array() {
-            for item in items {
-                if let (Some(title), Some(channel_id)) = (
-                    item["snippet"]["title"].as_str(),
-                    item["id"]["channelId"].as_str()
-                ) {
-                    // Simple matching - look for exact or close match
-                    if title.to_lowercase() == artist_name.to_lowercase() ||
-                       title.to_lowercase().contains(&artist_name.to_lowercase()) ||
-                       artist_name.to_lowercase().contains(&title.to_lowercase()) {
-                        
-                        let artist = Artist {
-                            name: title.to_string(),
-                            channel_id: channel_id.to_string(),
-                            subscriber_count: None,
-                            description: item["snippet"]["description"].as_str().map(|s| s.to_string()),
-                        };
-
-                        info!("Found matching artist: {}", artist.name);
-                        return Ok(Some(artist));
-                    }
-                }
-            }
-        }
-
-        warn!("No matching artist found for: {artist_name}");
-        Ok(None)
+    /// Downloads tracks for many artists at once with at most `parallel` artists in
+    /// flight, the same `buffer_unordered` pattern `subscribe_to_channels_concurrent`
+    /// uses. Returns one `(Artist, Result)` pair per input, in input order.
+    pub async fn download_artists_concurrent(
+        &self,
+        artists: &[Artist],
+        download_config: &crate::download::DownloadConfig,
+        parallel: usize,
+    ) -> Vec<(Artist, Result<Vec<crate::download::DownloadResult>>)> {
+        let parallel = parallel.max(1);
+
+        let mut results: Vec<(usize, Artist, Result<Vec<crate::download::DownloadResult>>)> =
+            stream::iter(artists.iter().cloned().enumerate())
+                .map(|(i, artist)| async move {
+                    let result = self.download_artist(&artist, download_config).await;
+                    (i, artist, result)
+                })
+                .buffer_unordered(parallel)
+                .collect()
+                .await;
+
+        results.sort_by_key(|(i, _, _)| *i);
+        results.into_iter().map(|(_, artist, result)| (artist, result)).collect()
     }
 
-    pub async fn subscribe_to_channel(&self, channel_id: &str) -> Result<()> {
-        self.subscribe_to_channel_with_retry(channel_id, self.config.settings.max_subscription_retries).await
-    }
-
-    async fn subscribe_to_channel_with_retry(&self, channel_id: &str, max_retries: u32) -> Result<()> {
-        info!("Subscribing to channel: {channel_id}");
-
-        let subscription = Subscription {
-            snippet: Some(google_youtube3::api::SubscriptionSnippet {
-                resource_id: Some(google_youtube3::api::ResourceId {
-                    channel_id: Some(channel_id.to_string()),
-                    kind: Some("youtube#channel".to_string()),
-                    ..Default::default()
-                }),
-                ..Default::default()
-            }),
-            ..Default::default()
+    /// Polls `artists`' channels for live broadcasts, printing a notification the first
+    /// time each one is seen. Runs until cancelled.
+    pub async fn watch_live_streams(&self, artists: &[Artist], poll_interval_secs: Option<u64>) -> Result<()> {
+        let watch_config = crate::live::WatchConfig {
+            poll_interval_seconds: poll_interval_secs.unwrap_or(self.config.watch.poll_interval_seconds),
+            ..self.config.watch.clone()
         };
+        crate::live::watch_for_live_streams(
+            &self.config.google.api_key,
+            artists,
+            &watch_config,
+            self.config.settings.parallel,
+        ).await
+    }
 
-        for attempt in 0..max_retries {
-            let req = self.youtube.subscriptions().insert(subscription.clone())
-                .add_part("snippet");
+    /// Writes `artists` as an OPML outline of channel RSS feeds, skipping mock entries.
+    pub fn export_opml(&self, artists: &[Artist], path: &std::path::Path) -> Result<()> {
+        crate::export::write_opml(artists, path)
+    }
 
-            match req.doit().await {
-                Ok(_) => {
-                    info!("Successfully subscribed to channel: {channel_id}");
-                    return Ok(());
-                }
-                Err(e) => {
-                    // Log detailed error information
-                    warn!("Subscription attempt {}/{} failed for {channel_id}: {e:?}", attempt + 1, max_retries);
-                    
-                    // Check for specific error types
-                    let error_msg = format!("{e}");
-                    if error_msg.contains("quotaExceeded") || error_msg.contains("rateLimitExceeded") {
-                        if attempt < max_retries - 1 {
-                            let delay = 2_u64.pow(attempt) * 1000; // Exponential backoff
-                            warn!("API quota/rate limit hit, retrying in {delay}ms");
-                            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
-                            continue;
-                        } else {
-                            anyhow::bail!("API quota exceeded after {} retries. Please wait and try again later, or request quota increase in Google Cloud Console", max_retries)
-                        }
-                    } else if error_msg.contains("forbidden") || error_msg.contains("403") {
-                        anyhow::bail!("Permission denied. Check OAuth consent screen settings and ensure your account is added as a test user")
-                    } else if error_msg.contains("channelNotFound") || error_msg.contains("404") {
-                        anyhow::bail!("Channel not found or no longer available")
-                    } else if error_msg.contains("subscriptionDuplicate") || error_msg.contains("already subscribed") {
-                        info!("Already subscribed to channel: {channel_id}");
-                        return Ok(()); // Treat duplicate as success
-                    } else if error_msg.contains("backend") || error_msg.contains("internal") {
-                        if attempt < max_retries - 1 {
-                            let delay = 1000 + (attempt as u64 * 500); // Linear backoff for server errors
-                            warn!("Server error, retrying in {delay}ms");
-                            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
-                            continue;
-                        } else {
-                            anyhow::bail!("Server error after {} retries: {e}", max_retries)
-                        }
-                    } else {
-                        anyhow::bail!("Subscription failed: {e}")
-                    }
-                }
-            }
+    /// Fetches each channel's RSS feed and writes the newest `limit` entries as a single
+    /// aggregated feed, skipping mock entries.
+    pub async fn export_rss(&self, artists: &[Artist], path: &std::path::Path, limit: usize) -> Result<()> {
+        crate::export::write_rss(artists, path, limit).await
+    }
+
+    /// Checks every subscribed channel's Atom feed for uploads newer than the recorded
+    /// watermark and prints them, advancing the watermark afterward.
+    pub async fn check_feeds(&self, artists: &[Artist], since_secs: Option<i64>, limit: usize) -> Result<()> {
+        crate::feed::check_for_new_uploads(&self.config.database.cache_db_path, artists, since_secs, limit).await
+    }
+
+    /// Fetches `channel_id`'s public Atom feed (no API quota cost) and renders a one-line
+    /// digest like "X — latest upload: Some Track (3 days ago)", doubling as a sanity
+    /// check that the channel actually exists and has uploads.
+    pub async fn verify_subscription(&self, channel_id: &str) -> Result<String> {
+        let digest = crate::export::fetch_channel_digest(channel_id, 1).await?;
+        match digest.latest_uploads.first() {
+            Some((title, published)) => Ok(format!(
+                "{} — latest upload: {} ({})",
+                digest.title, title, crate::export::humanize_published(published)
+            )),
+            None => Ok(format!("{} — no uploads found", digest.title)),
         }
-        
-        anyhow::bail!("Failed to subscribe after {} attempts", max_retries)
     }
 
-    #[allow(dead_code)]
-    pub async fn unsubscribe_from_channel(&self, subscription_id: &str) -> Result<()> {
-        info!("Unsubscribing from subscription: {subscription_id}");
+    /// Subscribes to many channels at once with at most `parallel` requests in flight. New
+    /// attempts are paced at least `delay` seconds apart via a `SharedRateLimiter`, and
+    /// unlike a plain `buffer_unordered` fan-out, a quota/rate-limit error from any one
+    /// worker widens that shared limiter, slowing down every other worker's next attempt
+    /// too instead of only backing off its own retry loop. Returns one `(channel_id,
+    /// Result)` pair per input, in input order.
+    pub async fn subscribe_to_channels_concurrent(&self, channel_ids: &[String], parallel: usize, delay: f64) -> Vec<(String, Result<()>)> {
+        let parallel = parallel.max(1);
+        let limiter = crate::subscribe::SharedRateLimiter::new(delay);
+        let max_retries = self.config.settings.max_subscription_retries;
+
+        let mut results: Vec<(usize, String, Result<()>)> = stream::iter(channel_ids.iter().cloned().enumerate())
+            .map(|(i, channel_id)| {
+                let limiter = limiter.clone();
+                async move {
+                    limiter.tick().await;
+                    let result = crate::subscribe::subscribe_with_retry(
+                        self.sub_backend.as_ref(),
+                        &channel_id,
+                        max_retries,
+                        Some(&limiter),
+                    ).await;
+                    (i, channel_id, result)
+                }
+            })
+            .buffer_unordered(parallel)
+            .collect()
+            .await;
 
-        let req = self.youtube.subscriptions().delete(subscription_id);
-        
+        results.sort_by_key(|(i, _, _)| *i);
+        results.into_iter().map(|(_, channel_id, result)| (channel_id, result)).collect()
+    }
+
+    /// Unsubscribes from `channel_id`. The Subscriptions.delete endpoint takes a
+    /// subscription resource id rather than a channel id, so this first looks up the
+    /// caller's own subscription to that channel before deleting it.
+    pub async fn unsubscribe_from_channel(&self, channel_id: &str) -> Result<()> {
+        info!("Unsubscribing from channel: {channel_id}");
+
+        let req = self.youtube.subscriptions().list(&vec!["id".to_string()])
+            .param("mine", "true")
+            .param("forChannelId", channel_id)
+            .max_results(1);
+        let (_, response) = req.doit().await?;
+        let Some(subscription_id) = response.items
+            .and_then(|items| items.into_iter().next())
+            .and_then(|sub| sub.id)
+        else {
+            warn!("Not subscribed to channel: {channel_id}");
+            return Ok(());
+        };
+
+        let req = self.youtube.subscriptions().delete(&subscription_id);
         req.doit().await
             .context("Failed to unsubscribe from channel")?;
 
-        info!("Successfully unsubscribed from: {subscription_id}");
+        info!("Successfully unsubscribed from: {channel_id}");
         Ok(())
     }
 }
@@ -768,7 +868,10 @@ pub fn parse_artists_file(content: &str) -> Result<Vec<String>> {
             continue;
         }
 
-        if artist_name.len() > 100 {
+        // Direct YouTube links (channel/handle/video/playlist) are exempt from the name
+        // length limit - they're URLs, not display names.
+        let is_direct_link = !matches!(entry::classify_entry(&artist_name), entry::ArtistEntry::Name(_));
+        if !is_direct_link && artist_name.len() > 100 {
             anyhow::bail!("Artist name too long on line {}: {}", line_num + 1, artist_name);
         }
 